@@ -0,0 +1,129 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::{BigInt, RandBigInt};
+use temp::{arithmetic::{JacobianPoint, Modular, Secp256k1Point}, field::FieldElement, generator::{mul_generator, GeneratorTable}, scalar::Scalar, secp256k1::{PrivateKey, PublicKey, RecoverableSignature, Secp256k1Params, Signature}};
+
+fn random_pair(p: &BigInt) -> (BigInt, FieldElement) {
+    let value = rand::thread_rng().gen_bigint_range(&BigInt::from(1), p);
+    let element = FieldElement::from_bigint(&value).unwrap();
+    (value, element)
+}
+
+fn field_ops(c: &mut Criterion) {
+    let p = Secp256k1Params::get().p.clone();
+    let (a, fa) = random_pair(&p);
+    let (b, fb) = random_pair(&p);
+
+    let mut group = c.benchmark_group("field");
+    group.bench_function("bigint mulmod", |bench| {
+        bench.iter(|| black_box(&a).mulmod(black_box(&b), &p))
+    });
+    group.bench_function("field mul", |bench| {
+        bench.iter(|| black_box(fa) * black_box(fb))
+    });
+    group.bench_function("bigint addmod", |bench| {
+        bench.iter(|| black_box(&a).addmod(black_box(&b), &p))
+    });
+    group.bench_function("field add", |bench| {
+        bench.iter(|| black_box(fa) + black_box(fb))
+    });
+    group.bench_function("bigint invmod", |bench| {
+        bench.iter(|| black_box(&a).invmod(&p))
+    });
+    group.bench_function("field invert", |bench| {
+        bench.iter(|| black_box(fa).invert())
+    });
+    group.finish();
+}
+
+fn scalar_ops(c: &mut Criterion) {
+    let n = Secp256k1Params::get().n.clone();
+    let mut rng = rand::thread_rng();
+    let (a, b) = (rng.gen_bigint_range(&BigInt::from(1), &n), rng.gen_bigint_range(&BigInt::from(1), &n));
+    let (sa, sb) = (Scalar::from_bigint(&a).unwrap(), Scalar::from_bigint(&b).unwrap());
+
+    let mut group = c.benchmark_group("scalar");
+    group.bench_function("bigint mulmod", |bench| {
+        bench.iter(|| black_box(&a).mulmod(black_box(&b), &n))
+    });
+    group.bench_function("scalar mul", |bench| {
+        bench.iter(|| black_box(sa) * black_box(sb))
+    });
+    group.bench_function("bigint invmod", |bench| {
+        bench.iter(|| black_box(&a).invmod(&n))
+    });
+    group.bench_function("scalar invert", |bench| {
+        bench.iter(|| black_box(sa).invert())
+    });
+    group.finish();
+}
+
+fn point_ops(c: &mut Criterion) {
+    let g = Secp256k1Params::get().g.clone();
+    let k = Scalar::random(&mut rand::thread_rng());
+
+    let mut group = c.benchmark_group("point");
+    group.bench_function("affine add", |bench| {
+        let h = g.times_two();
+        bench.iter(|| black_box(&g) + black_box(&h))
+    });
+    group.bench_function("jacobian add", |bench| {
+        let (a, b) = (JacobianPoint::from(&g), JacobianPoint::from(&g).double());
+        bench.iter(|| black_box(a) + black_box(b))
+    });
+    group.bench_function("jacobian double", |bench| {
+        let a = JacobianPoint::from(&g);
+        bench.iter(|| black_box(a).double())
+    });
+    group.bench_function("times", |bench| {
+        bench.iter(|| black_box(&g).times(black_box(&k)))
+    });
+    group.bench_function("times_ct", |bench| {
+        bench.iter(|| black_box(&g).times_ct(black_box(&k)))
+    });
+    GeneratorTable::get();
+    group.bench_function("mul_generator", |bench| {
+        bench.iter(|| mul_generator(black_box(&k)))
+    });
+    let p = g.times(&k);
+    let v = Scalar::random(&mut rand::thread_rng());
+    group.bench_function("u·G + v·P separately", |bench| {
+        bench.iter(|| mul_generator(black_box(&k)) + JacobianPoint::from(&p).times(black_box(&v)))
+    });
+    group.bench_function("mul_add", |bench| {
+        bench.iter(|| Secp256k1Point::mul_add(black_box(&k), &p, black_box(&v)))
+    });
+    group.finish();
+}
+
+fn ecdsa_ops(c: &mut Criterion) {
+    let messages: Vec<String> = (0..64).map(|i| format!("msg {}", i)).collect();
+    let priv_keys: Vec<PrivateKey> = messages.iter().map(|_| PrivateKey::generate()).collect();
+    let pub_keys: Vec<PublicKey> = priv_keys.iter().map(|pk| PublicKey::new(pk).unwrap()).collect();
+    let signs: Vec<Signature> = messages.iter().zip(&priv_keys)
+        .map(|(msg, pk)| Signature::sign_message(msg, pk).unwrap())
+        .collect();
+    let items: Vec<(&str, &PublicKey, &Signature)> = messages.iter().zip(&pub_keys).zip(&signs)
+        .map(|((msg, pk), sign)| (msg.as_str(), pk, sign))
+        .collect();
+
+    let mut group = c.benchmark_group("ecdsa");
+    group.bench_function("validate x64", |bench| {
+        bench.iter(|| items.iter().all(|(msg, pk, sign)| Signature::validate(msg, pk, sign).is_ok()))
+    });
+    group.bench_function("verify_each_batch_inverted x64", |bench| {
+        bench.iter(|| Signature::verify_each_batch_inverted(black_box(&items)))
+    });
+    let recoverable: Vec<RecoverableSignature> = messages.iter().zip(&priv_keys)
+        .map(|(msg, pk)| RecoverableSignature::sign_message(msg, pk).unwrap())
+        .collect();
+    let recoverable_items: Vec<(&str, &PublicKey, &RecoverableSignature)> = messages.iter().zip(&pub_keys).zip(&recoverable)
+        .map(|((msg, pk), sign)| (msg.as_str(), pk, sign))
+        .collect();
+    group.bench_function("recoverable verify_batch x64", |bench| {
+        bench.iter(|| RecoverableSignature::verify_batch(black_box(&recoverable_items)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, field_ops, scalar_ops, point_ops, ecdsa_ops);
+criterion_main!(benches);
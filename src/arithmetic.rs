@@ -1,10 +1,11 @@
 use std::{fmt::Display, mem::size_of, ops::{Add, Div, Mul, Neg, Rem, Sub}};
 
 use num_bigint::BigInt;
-use num_traits::{FromPrimitive, One, Pow, Signed, Zero};
-use secp256k1::Secp256k1Params;
+use num_traits::{One, Zero};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroizing;
 
-use crate::secp256k1;
+use crate::{curve::{CurveParams, Field, ScalarField}, error::Error, field::adc, secp256k1::Secp256k1Params, wnaf::{self, OddMultiples}};
 
 pub trait Modular: Add + Div + Mul + Sub + Sized
 {
@@ -20,7 +21,7 @@ pub trait Modular: Add + Div + Mul + Sub + Sized
 impl Modular for BigInt {
 
     fn addmod(&self, rhs: &Self, modulus: &Self) -> Self {
-        self.modulus(modulus) + rhs.modulus(modulus)
+        (self.modulus(modulus) + rhs.modulus(modulus)).modulus(modulus)
     }
 
     fn modulus(&self, rhs: &Self) -> Self {
@@ -45,7 +46,9 @@ impl Modular for BigInt {
     }
     
     fn invmod(&self, modulus: &Self) -> Option<Self> {
-        let (gcd, x, _) = Modular::gcd(self, modulus);
+        // gcd() mixes floored remainders with truncated quotients, which only
+        // agree for non-negative inputs, so reduce first.
+        let (gcd, x, _) = Modular::gcd(&self.modulus(modulus), modulus);
         if gcd.eq(&One::one()) {
             Some((x.modulus(modulus) + modulus).modulus(modulus))
         } else {
@@ -63,146 +66,154 @@ impl Modular for BigInt {
     } 
 }
 
-#[derive(Clone, Debug)]
-pub struct Secp256k1Point {
-    pub x: Option<num_bigint::BigInt>,
-    pub y: Option<num_bigint::BigInt>
+/// Affine point on the curve `C`; `None` coordinates stand for the point
+/// at infinity.
+#[derive(Debug)]
+pub struct AffinePoint<C: CurveParams> {
+    pub x: Option<C::Field>,
+    pub y: Option<C::Field>
 }
 
-impl Secp256k1Point {
+/// An affine secp256k1 point.
+pub type Secp256k1Point = AffinePoint<Secp256k1Params>;
 
-    pub fn init(x: BigInt) -> Self {
-        let a = Secp256k1Params::get().a;
-        let b = Secp256k1Params::get().b;
-        let p = Secp256k1Params::get().p;
-        let y = BigInt::sqrt(
-            &(&x.clone().pow(3u8) + &a * &x + &b).modulus(&p)
-        );
-        Self { x: Some(x), y: Some(y) }
+impl<C: CurveParams> AffinePoint<C> {
+
+    /// Lifts `x` to a curve point, picking the `y` whose parity matches
+    /// `y_is_odd`.
+    pub fn from_x(x: C::Field, y_is_odd: bool) -> Result<Self, Error> {
+        let params = C::get();
+        let alpha = x.square() * x + params.a() * x + params.b();
+        let beta = alpha.sqrt().ok_or(Error::PointNotOnCurve)?;
+        let y = if beta.is_odd() == y_is_odd {
+            beta
+        } else {
+            -beta
+        };
+        Ok(Self { x: Some(x), y: Some(y) })
     }
 
-    pub fn free_dot(x: BigInt, y: BigInt) -> Self {
-        Self { x: Some(x), y: Some(y) }
+    /// Checks y² = x³ + ax + b (mod p). The point at infinity counts as on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        let params = C::get();
+        match (&self.x, &self.y) {
+            (Some(x), Some(y)) => y.square() == x.square() * *x + params.a() * *x + params.b(),
+            (None, None) => true,
+            _ => false,
+        }
     }
 
-    pub fn times<T: ToBits>(&self, n: &T) -> Secp256k1Point {
-        let bits = n.to_bits();
-        let this = (*self).clone();
-        let mut res = (*self).clone();
-        for i in bits {
-            if i {
-                res = res.times_two() + this.clone();
-            } else {
-                res = res.times_two();
-            }
+    /// Checked constructor: fails unless `(x, y)` is a point on the curve.
+    pub fn new(x: C::Field, y: C::Field) -> Result<Self, Error> {
+        let point = Self::free_dot(x, y);
+        if !point.is_on_curve() {
+            return Err(Error::PointNotOnCurve);
         }
-        res
+        Ok(point)
+    }
+
+    /// Builds a point without any validation; use `new` for untrusted input.
+    pub fn free_dot(x: C::Field, y: C::Field) -> Self {
+        Self { x: Some(x), y: Some(y) }
+    }
+
+    /// Computes k·self for a public scalar, with whatever variable-time
+    /// method the curve provides (`CurveParams::mul`).
+    pub fn times(&self, k: &C::Scalar) -> Self {
+        C::mul(self, k).to_affine()
     }
 
-    pub fn times_two(&self) -> Secp256k1Point {
-        if self.eq(&Secp256k1Point::zero()) {
-            return Secp256k1Point::zero();
+    /// Constant-time k·self for secret scalars; see `ProjectivePoint::times_ct`.
+    pub fn times_ct(&self, k: &C::Scalar) -> Self {
+        ProjectivePoint::from(self).times_ct(k).to_affine()
+    }
+
+    /// u·G + v·P; see `CurveParams::mul_add`. Variable time.
+    pub fn mul_add(u: &C::Scalar, p: &Self, v: &C::Scalar) -> Self {
+        C::mul_add(u, p, v).to_affine()
+    }
+
+    /// Σ kᵢ·Pᵢ with interleaved wNAF, or with Pippenger's buckets from
+    /// `wnaf::PIPPENGER_THRESHOLD` terms on. Variable time.
+    pub fn lincomb(terms: &[(C::Scalar, Self)]) -> Self {
+        if terms.len() >= wnaf::PIPPENGER_THRESHOLD {
+            return wnaf::pippenger(terms).to_affine();
         }
+        let wnaf_terms: Vec<(Vec<i8>, OddMultiples<C>)> = terms
+            .iter()
+            .map(|(k, p)| {
+                let table = OddMultiples::Jacobian(wnaf::odd_multiples(p, wnaf::POINT_WINDOW));
+                (wnaf::wnaf(k, wnaf::POINT_WINDOW), table)
+            })
+            .collect();
+        wnaf::strauss(&wnaf_terms).to_affine()
+    }
+
+    pub fn times_two(&self) -> Self {
         if let (
             Some(x), 
             Some(y), 
-        ) = (&self.x, &self.y) {
-            let three: BigInt = BigInt::from_i8(3i8)
-                .unwrap();
-            let two: BigInt = BigInt::from_i8(2i8)
-                .unwrap();
-            let a: BigInt = Secp256k1Params::get().a;
-            let modulus: BigInt = Secp256k1Params::get().p;
-            let lambda_1: BigInt = &(three * x.clone().pow(2u8)) + &a;
-            let lambda_2: BigInt = (&two * y).invmod(&modulus).unwrap();
-            let lambda = (&lambda_1 * &lambda_2).modulus(&modulus);
-            let res_x = (&lambda.clone().pow(2u8) - x - x).modulus(&modulus);
-            let res_y = (&(lambda * (x - &res_x)) - y).modulus(&modulus);
-            Secp256k1Point {
+        ) = (self.x, self.y) {
+            // A vertical tangent (y = 0) doubles to the point at infinity.
+            let lambda_2 = match y.double().invert() {
+                Some(inv) => inv,
+                None => return Self::zero(),
+            };
+            let lambda_1 = C::Field::from_u64(3) * x.square() + C::get().a();
+            let lambda = lambda_1 * lambda_2;
+            let res_x = lambda.square() - x - x;
+            let res_y = lambda * (x - res_x) - y;
+            AffinePoint {
                 x: Some(res_x),
                 y: Some(res_y),
             }
         } else {
-            return Secp256k1Point::zero();
+            Self::zero()
         }
     }
 }
 
-impl Zero for Secp256k1Point {
+impl<C: CurveParams> Clone for AffinePoint<C> {
+    fn clone(&self) -> Self {
+        Self { x: self.x, y: self.y }
+    }
+}
+
+impl<C: CurveParams> Zero for AffinePoint<C> {
     fn zero() -> Self {
-        Secp256k1Point { x: None::<BigInt>, y: None::<BigInt> }
+        AffinePoint { x: None, y: None }
     }
 
     fn is_zero(&self) -> bool {
-        self.eq(&Secp256k1Point { x: None::<BigInt>, y: None::<BigInt> })
+        self.x.is_none() && self.y.is_none()
     }
 }
 
-impl Default for Secp256k1Point {
+impl<C: CurveParams> Default for AffinePoint<C> {
     fn default() -> Self {
-        Self { x: Default::default(), y: Default::default()}
+        Self::zero()
     }
 }
 
-impl PartialEq for Secp256k1Point {
+impl<C: CurveParams> PartialEq for AffinePoint<C> {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y
     }
 }
 
-impl Add for Secp256k1Point {
+impl<C: CurveParams> Add for AffinePoint<C> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        if self.eq(&rhs) {
-            if self.is_zero() {
-                return Secp256k1Point::zero();
-            }
-            return self.times_two();
-        }
-        if self.is_zero() {
-            return rhs;
-        }
-        if rhs.is_zero() {
-            return self;
-        }
-        if (self.x).eq(&rhs.x) {
-            return Secp256k1Point {
-                x: None,
-                y: None,
-            };
-        }
-        if let (
-            Some(a_x), 
-            Some(a_y), 
-            Some(b_x), 
-            Some(b_y)
-        ) = (&self.x, &self.y, &rhs.x, &rhs.y) {
-            let modulus = Secp256k1Params::get().p;
-            let lambda = ((b_y - a_y) * (b_x - a_x).invmod(&modulus).unwrap()).modulus(&modulus);
-            let res_x = (&lambda.clone().pow(2u8) - a_x - b_x).modulus(&modulus);
-            let res_y = (&lambda * &(a_x - &res_x) - a_y).modulus(&modulus);
-            Secp256k1Point {
-                x: Some(res_x),
-                y: Some(res_y),
-            }
-        } else {
-            Secp256k1Point {
-                x: None,
-                y: None,
-            }
-        }
+        &self + &rhs
     }
 }
 
-impl<'a, 'b> Add<&'b Secp256k1Point> for &'a Secp256k1Point {
-    type Output = Secp256k1Point;
+impl<'b, C: CurveParams> Add<&'b AffinePoint<C>> for &AffinePoint<C> {
+    type Output = AffinePoint<C>;
 
-    fn add(self, rhs: &'b Secp256k1Point) -> Self::Output {
+    fn add(self, rhs: &'b AffinePoint<C>) -> Self::Output {
         if self.eq(rhs) {
-            if self.is_zero() {
-                return Secp256k1Point::zero();
-            }
             return self.times_two();
         }
         if self.is_zero() {
@@ -211,58 +222,42 @@ impl<'a, 'b> Add<&'b Secp256k1Point> for &'a Secp256k1Point {
         if rhs.is_zero() {
             return self.clone();
         }
-        if (self.x).eq(&rhs.x) {
-            return Secp256k1Point {
-                x: None,
-                y: None,
-            };
-        }
         if let (
             Some(a_x), 
             Some(a_y), 
             Some(b_x), 
             Some(b_y)
-        ) = (&self.x, &self.y, &rhs.x, &rhs.y) {
-            let modulus = Secp256k1Params::get().p;
-            let lambda = ((b_y - a_y) * (b_x - a_x).invmod(&modulus).unwrap()).modulus(&modulus);
-            let res_x = (&lambda.clone().pow(2u8) - a_x - b_x).modulus(&modulus);
-            let res_y = (&lambda * &(a_x - &res_x) - a_y).modulus(&modulus);
-            Secp256k1Point {
+        ) = (self.x, self.y, rhs.x, rhs.y) {
+            // Same x but different points: P + (-P).
+            let inv = match (b_x - a_x).invert() {
+                Some(inv) => inv,
+                None => return AffinePoint::zero(),
+            };
+            let lambda = (b_y - a_y) * inv;
+            let res_x = lambda.square() - a_x - b_x;
+            let res_y = lambda * (a_x - res_x) - a_y;
+            AffinePoint {
                 x: Some(res_x),
                 y: Some(res_y),
             }
         } else {
-            Secp256k1Point {
-                x: None,
-                y: None,
-            }
+            AffinePoint::zero()
         }
     }
 }
 
-impl Neg for Secp256k1Point {
+impl<C: CurveParams> Neg for AffinePoint<C> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        if self.eq(&Secp256k1Point::zero()) {
-            return Secp256k1Point::zero();
-        }
-        let modulus = Secp256k1Params::get().p;
-        if let (
-            Some(x),
-            Some(y)
-        ) = (self.x, self.y) {
-            Secp256k1Point {
-                x: Some(x),
-                y: Some((-y).modulus(&modulus)),
-            }
-        } else {
-            Secp256k1Point::zero()
+        match (self.x, self.y) {
+            (Some(x), Some(y)) => AffinePoint::free_dot(x, -y),
+            _ => AffinePoint::zero(),
         }
     }
 }
 
-impl Display for Secp256k1Point {
+impl<C: CurveParams> Display for AffinePoint<C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let (
             Some(x),
@@ -275,6 +270,437 @@ impl Display for Secp256k1Point {
     }
 }
 
+/// Point in Jacobian coordinates: (X, Y, Z) stands for the affine point
+/// (X/Z², Y/Z³), and Z = 0 is the point at infinity. Addition and doubling
+/// need no field inversion; `to_affine` pays for a single one at the end.
+#[derive(Debug)]
+pub struct ProjectivePoint<C: CurveParams> {
+    pub x: C::Field,
+    pub y: C::Field,
+    pub z: C::Field,
+}
+
+/// A secp256k1 point in Jacobian coordinates.
+pub type JacobianPoint = ProjectivePoint<Secp256k1Params>;
+
+impl<C: CurveParams> ProjectivePoint<C> {
+    /// Converts back to affine coordinates with one inversion of Z.
+    pub fn to_affine(&self) -> AffinePoint<C> {
+        match self.z.invert() {
+            Some(z_inv) => {
+                let z_inv2 = z_inv.square();
+                AffinePoint::free_dot(self.x * z_inv2, self.y * z_inv2 * z_inv)
+            }
+            None => AffinePoint::zero(),
+        }
+    }
+
+    /// dbl-2009-l from the Explicit-Formulas Database when a = 0, and
+    /// dbl-2007-bl otherwise. A point with Y = 0 gets Z3 = 0, i.e. doubles
+    /// to infinity.
+    pub fn double(&self) -> Self {
+        let a = C::get().a();
+        if !a.is_zero() {
+            let xx = self.x.square();
+            let yy = self.y.square();
+            let yyyy = yy.square();
+            let zz = self.z.square();
+            let s = ((self.x + yy).square() - xx - yyyy).double();
+            let m = xx.double() + xx + a * zz.square();
+            let x = m.square() - s.double();
+            let y = m * (s - x) - yyyy.double().double().double();
+            let z = (self.y + self.z).square() - yy - zz;
+            return ProjectivePoint { x, y, z };
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+        let x = f - d.double();
+        let y = e * (d - x) - c.double().double().double();
+        let z = (self.y * self.z).double();
+        ProjectivePoint { x, y, z }
+    }
+
+    /// u·G + v·P; see `CurveParams::mul_add`. Variable time.
+    pub fn mul_add(u: &C::Scalar, p: &AffinePoint<C>, v: &C::Scalar) -> Self {
+        C::mul_add(u, p, v)
+    }
+
+    /// Whether the affine x coordinate of this point is `x`, checked as
+    /// X = x·Z² so that no inversion is needed. False for the point at infinity.
+    pub fn has_affine_x(&self, x: &C::Field) -> bool {
+        !self.is_zero() && self.x == *x * self.z.square()
+    }
+
+    /// Converts many points at once with Montgomery's trick: one inversion
+    /// plus three multiplications per point instead of an inversion each.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<AffinePoint<C>> {
+        let mut prefix = Vec::with_capacity(points.len());
+        let mut acc = C::Field::ONE;
+        for point in points {
+            prefix.push(acc);
+            if !point.is_zero() {
+                acc = acc * point.z;
+            }
+        }
+        let mut inv = acc.invert().expect("product of non-zero Z coordinates");
+        let mut out = vec![AffinePoint::zero(); points.len()];
+        for (i, point) in points.iter().enumerate().rev() {
+            if point.is_zero() {
+                continue;
+            }
+            let z_inv = inv * prefix[i];
+            inv = inv * point.z;
+            let z_inv2 = z_inv.square();
+            out[i] = AffinePoint::free_dot(point.x * z_inv2, point.y * z_inv2 * z_inv);
+        }
+        out
+    }
+
+    /// Mixed addition (madd-2007-bl) of the affine point (x, y), which must
+    /// not be the point at infinity. Cheaper than a full Jacobian addition
+    /// since Z2 = 1.
+    pub fn add_affine(&self, x: &C::Field, y: &C::Field) -> Self {
+        if self.is_zero() {
+            return ProjectivePoint { x: *x, y: *y, z: C::Field::ONE };
+        }
+        let (sum, h, r) = self.madd(x, y);
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { Self::zero() };
+        }
+        sum
+    }
+
+    /// `add_affine` without branches, for sums that involve secret data.
+    /// The doubling and the lift of (x, y) for an infinite `self` are always
+    /// computed and picked with masked selects. When self = -(x, y) the
+    /// formula itself yields Z3 = 0, the point at infinity.
+    pub fn add_affine_ct(&self, x: &C::Field, y: &C::Field) -> Self {
+        let (mut sum, h, r) = self.madd(x, y);
+        let self_is_zero = self.z.ct_eq(&C::Field::ZERO);
+        let is_double = h.ct_eq(&C::Field::ZERO) & r.ct_eq(&C::Field::ZERO) & !self_is_zero;
+        sum.conditional_assign(&self.double(), is_double);
+        sum.conditional_assign(&ProjectivePoint { x: *x, y: *y, z: C::Field::ONE }, self_is_zero);
+        sum
+    }
+
+    /// madd-2007-bl itself, along with h = U2 - X1 and r = 2·(S2 - Y1): the
+    /// result is only right when h ≠ 0 and `self` is finite.
+    fn madd(&self, x: &C::Field, y: &C::Field) -> (Self, C::Field, C::Field) {
+        let z1z1 = self.z.square();
+        let u2 = *x * z1z1;
+        let s2 = *y * self.z * z1z1;
+        let h = u2 - self.x;
+        let r = (s2 - self.y).double();
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let v = self.x * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (self.y * j).double();
+        let z3 = (self.z + h).square() - z1z1 - hh;
+        (ProjectivePoint { x: x3, y: y3, z: z3 }, h, r)
+    }
+
+    /// Computes k·self with plain double-and-add. Variable time: only use
+    /// it with public scalars.
+    pub fn times(&self, k: &C::Scalar) -> Self {
+        let mut res = Self::zero();
+        for i in (0..C::Scalar::NUM_BITS).rev() {
+            res = res.double();
+            if k.bit(i) {
+                res = res + *self;
+            }
+        }
+        res
+    }
+
+    /// Montgomery ladder for secret scalars (private keys, nonces). Every bit
+    /// costs one addition and one doubling, and the two ladder registers are
+    /// exchanged with a masked swap rather than a branch on the bit.
+    ///
+    /// The scalar is padded to k + n or k + 2n, whichever has bit `NUM_BITS`
+    /// set, so the ladder always runs over the same length and never starts
+    /// from the point at infinity. The padded copies of k are wiped on drop.
+    pub fn times_ct(&self, k: &C::Scalar) -> Self {
+        let n = C::Scalar::MODULUS;
+        let add_n = |a: &[u64]| {
+            let mut out = Zeroizing::new(vec![0u64; a.len()]);
+            let mut carry = 0;
+            for (i, limb) in out.iter_mut().enumerate() {
+                (*limb, carry) = adc(a[i], n.get(i).copied().unwrap_or(0), carry);
+            }
+            out
+        };
+        let top = C::Scalar::NUM_BITS;
+        // One spare limb for the carry, reserved up front so that growing k
+        // never leaves an unwiped copy behind.
+        let limbs = k.limbs();
+        let mut k = Zeroizing::new(Vec::with_capacity(limbs.len() + 1));
+        k.extend_from_slice(limbs);
+        k.push(0);
+        let k_n = add_n(&k);
+        let mut padded = add_n(&k_n);
+        let use_k_n = Choice::from(((k_n[top / 64] >> (top % 64)) & 1) as u8);
+        for (limb, k_n_limb) in padded.iter_mut().zip(k_n.iter()) {
+            limb.conditional_assign(k_n_limb, use_k_n);
+        }
+
+        let mut r0 = *self;
+        let mut r1 = self.double();
+        for i in (0..top).rev() {
+            let bit = Choice::from(((padded[i / 64] >> (i % 64)) & 1) as u8);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0 + r1;
+            r0 = r0.double();
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
+impl<C: CurveParams> Clone for ProjectivePoint<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CurveParams> Copy for ProjectivePoint<C> {}
+
+impl<C: CurveParams> From<&AffinePoint<C>> for ProjectivePoint<C> {
+    fn from(point: &AffinePoint<C>) -> Self {
+        match (point.x, point.y) {
+            (Some(x), Some(y)) => ProjectivePoint { x, y, z: C::Field::ONE },
+            _ => ProjectivePoint::zero(),
+        }
+    }
+}
+
+impl<C: CurveParams> Zero for ProjectivePoint<C> {
+    fn zero() -> Self {
+        ProjectivePoint { x: C::Field::ONE, y: C::Field::ONE, z: C::Field::ZERO }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl<C: CurveParams> PartialEq for ProjectivePoint<C> {
+    /// Compares the underlying affine points without normalizing.
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = self.z.square();
+                let z2z2 = other.z.square();
+                self.x * z2z2 == other.x * z1z1
+                    && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveParams> Add for ProjectivePoint<C> {
+    type Output = Self;
+
+    /// add-2007-bl from the Explicit-Formulas Database. Equal inputs fall
+    /// back to `double`, opposite ones give the point at infinity.
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.is_zero() {
+            return rhs;
+        }
+        if rhs.is_zero() {
+            return self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { Self::zero() };
+        }
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+        let x = r.square() - j - v.double();
+        let y = r * (v - x) - (s1 * j).double();
+        let z = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+        ProjectivePoint { x, y, z }
+    }
+}
+
+impl<C: CurveParams> Neg for ProjectivePoint<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        ProjectivePoint { x: self.x, y: -self.y, z: self.z }
+    }
+}
+
+impl<C: CurveParams> ConditionallySelectable for ProjectivePoint<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        ProjectivePoint {
+            x: C::Field::conditional_select(&a.x, &b.x, choice),
+            y: C::Field::conditional_select(&a.y, &b.y, choice),
+            z: C::Field::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Point in homogeneous projective coordinates: (X, Y, Z) stands for the
+/// affine point (X/Z, Y/Z), and (0, 1, 0) is the point at infinity.
+///
+/// Addition and doubling use the complete formulas of Renes, Costello and
+/// Batina (EUROCRYPT 2016, algorithms 1 and 3) for arbitrary a. They are
+/// correct for every pair of inputs on a prime-order curve, doubling and the
+/// point at infinity included, so there are no special cases to branch on.
+#[derive(Debug)]
+pub struct HomogeneousPoint<C: CurveParams> {
+    pub x: C::Field,
+    pub y: C::Field,
+    pub z: C::Field,
+}
+
+impl<C: CurveParams> HomogeneousPoint<C> {
+    pub fn identity() -> Self {
+        HomogeneousPoint { x: C::Field::ZERO, y: C::Field::ONE, z: C::Field::ZERO }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn to_affine(&self) -> AffinePoint<C> {
+        match self.z.invert() {
+            Some(z_inv) => AffinePoint::free_dot(self.x * z_inv, self.y * z_inv),
+            None => AffinePoint::zero(),
+        }
+    }
+
+    /// (X, Y, Z) in Jacobian coordinates is (XZ, YZ², Z); no inversion needed.
+    pub fn to_jacobian(&self) -> ProjectivePoint<C> {
+        if self.is_identity() {
+            return ProjectivePoint::zero();
+        }
+        ProjectivePoint { x: self.x * self.z, y: self.y * self.z.square(), z: self.z }
+    }
+
+    /// Algorithm 3 of Renes–Costello–Batina.
+    pub fn double(&self) -> Self {
+        let params = C::get();
+        let (a, b3) = (params.a(), params.b().double() + params.b());
+        let (x, y, z) = (self.x, self.y, self.z);
+        let t0 = x.square();
+        let t1 = y.square();
+        let t2 = z.square();
+        let t3 = (x * y).double();
+        let z3 = (x * z).double();
+        let y3 = a * z3 + b3 * t2;
+        let x3 = t1 - y3;
+        let y3 = x3 * (t1 + y3);
+        let x3 = t3 * x3;
+        let z3 = b3 * z3;
+        let t2 = a * t2;
+        let t3 = a * (t0 - t2) + z3;
+        let t0 = (t0.double() + t0 + t2) * t3;
+        let y3 = y3 + t0;
+        let t2 = (y * z).double();
+        let x3 = x3 - t2 * t3;
+        let z3 = (t2 * t1).double().double();
+        HomogeneousPoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Montgomery ladder over all `NUM_BITS` bits of k with masked swaps.
+    /// With complete formulas neither register needs special handling, so
+    /// the sequence of field operations is the same for every k.
+    pub fn times_ct(&self, k: &C::Scalar) -> Self {
+        let mut r0 = Self::identity();
+        let mut r1 = *self;
+        for i in (0..C::Scalar::NUM_BITS).rev() {
+            let bit = Choice::from(k.bit(i) as u8);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0 + r1;
+            r0 = r0.double();
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
+impl<C: CurveParams> Clone for HomogeneousPoint<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CurveParams> Copy for HomogeneousPoint<C> {}
+
+impl<C: CurveParams> From<&AffinePoint<C>> for HomogeneousPoint<C> {
+    fn from(point: &AffinePoint<C>) -> Self {
+        match (point.x, point.y) {
+            (Some(x), Some(y)) => HomogeneousPoint { x, y, z: C::Field::ONE },
+            _ => HomogeneousPoint::identity(),
+        }
+    }
+}
+
+impl<C: CurveParams> Add for HomogeneousPoint<C> {
+    type Output = Self;
+
+    /// Algorithm 1 of Renes–Costello–Batina.
+    fn add(self, rhs: Self) -> Self::Output {
+        let params = C::get();
+        let (a, b3) = (params.a(), params.b().double() + params.b());
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t2 = self.z * rhs.z;
+        let t3 = (self.x + self.y) * (rhs.x + rhs.y) - (t0 + t1);
+        let t4 = (self.x + self.z) * (rhs.x + rhs.z) - (t0 + t2);
+        let t5 = (self.y + self.z) * (rhs.y + rhs.z) - (t1 + t2);
+        let z3 = a * t4 + b3 * t2;
+        let x3 = t1 - z3;
+        let z3 = t1 + z3;
+        let y3 = x3 * z3;
+        let t1 = t0.double() + t0;
+        let t2 = a * t2;
+        let t4 = b3 * t4;
+        let t1 = t1 + t2;
+        let t2 = a * (t0 - t2);
+        let t4 = t4 + t2;
+        let y3 = y3 + t1 * t4;
+        let x3 = t3 * x3 - t5 * t4;
+        let z3 = t5 * z3 + t3 * t1;
+        HomogeneousPoint { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl<C: CurveParams> Neg for HomogeneousPoint<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        HomogeneousPoint { x: self.x, y: -self.y, z: self.z }
+    }
+}
+
+impl<C: CurveParams> ConditionallySelectable for HomogeneousPoint<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        HomogeneousPoint {
+            x: C::Field::conditional_select(&a.x, &b.x, choice),
+            y: C::Field::conditional_select(&a.y, &b.y, choice),
+            z: C::Field::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
 pub trait ToBits: Add + Div + Mul + Sub + Sized {
     fn to_bits(&self) -> Vec<bool>;  
 }
@@ -287,8 +713,9 @@ impl ToBits for u64 {
 }
 
 impl ToBits for BigInt {
+    /// Big-endian bits of the magnitude; the sign is ignored.
     fn to_bits(&self) -> Vec<bool> {
-        let bytes = self.to_signed_bytes_be();
+        let (_, bytes) = self.to_bytes_be();
         let mut bits: Vec<bool> = Vec::new();
         for byte in bytes {
             for i in 0..8 {
@@ -306,7 +733,7 @@ mod tests {
     use num_bigint::BigInt;
     use num_traits::FromPrimitive;
 
-    use crate::{ecdsa::PrivateKey, secp256k1::Secp256k1Params};
+    use crate::{scalar::Scalar, secp256k1::{PrivateKey, Secp256k1Params}};
 
     use super::Modular;
 
@@ -329,16 +756,16 @@ mod tests {
 
     #[test]
     fn test_times_two() {
-        let g = Secp256k1Params::get().g;
+        let g = Secp256k1Params::get().g.clone();
         let times_two = g.times_two();
         println!("g * 2: {:?}", times_two);
     }
 
     #[test]
     fn test_times() {
-        let times = PrivateKey::generate().0;
-        let g = Secp256k1Params::get().g;
-        let s = BigInt::from_u16(12u16).unwrap();
+        let times = PrivateKey::generate().to_bytes();
+        let g = Secp256k1Params::get().g.clone();
+        let s = Scalar::from_u64(12);
         let pub_key_value = g.times(&s);
         println!("public key value: {:?}", pub_key_value);
     }
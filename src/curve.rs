@@ -0,0 +1,181 @@
+use std::{fmt::{Debug, Display}, ops::{Add, Mul, Neg, Sub}};
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Signed;
+use rand::RngCore;
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{arithmetic::{AffinePoint, HomogeneousPoint, ProjectivePoint}, wnaf::{self, OddMultiples}};
+
+/// Arithmetic in the coordinate field GF(p) that the generic point code
+/// relies on.
+pub trait Field:
+    Copy + Debug + Display + Default + Eq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + ConditionallySelectable + ConstantTimeEq
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Length of the big-endian encoding.
+    const BYTES: usize;
+
+    fn from_u64(value: u64) -> Self;
+    fn is_zero(&self) -> bool;
+    fn is_odd(&self) -> bool;
+    fn square(&self) -> Self;
+    fn double(&self) -> Self;
+    fn invert(&self) -> Option<Self>;
+    fn sqrt(&self) -> Option<Self>;
+
+    /// Parses exactly `BYTES` big-endian bytes, rejecting values ≥ p.
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self>;
+    fn to_be_bytes(&self) -> Vec<u8>;
+
+    /// Converts a `BigInt` in `[0, p)`; anything else gives `None`.
+    fn from_bigint(value: &BigInt) -> Option<Self> {
+        from_bigint_with(value, Self::BYTES, Self::from_be_bytes)
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &self.to_be_bytes())
+    }
+}
+
+/// Arithmetic modulo the group order n.
+pub trait ScalarField:
+    Copy + Debug + Display + Default + Eq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + ConditionallySelectable + ConstantTimeEq + Zeroize
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Length of the big-endian encoding.
+    const BYTES: usize;
+    /// Bit length of n.
+    const NUM_BITS: usize;
+    /// n, least significant limb first.
+    const MODULUS: &'static [u64];
+
+    fn from_u64(value: u64) -> Self;
+    fn is_zero(&self) -> bool;
+    /// Whether the value is above ⌊n / 2⌋.
+    fn is_high(&self) -> bool;
+    fn invert(&self) -> Option<Self>;
+    /// The canonical value, least significant limb first.
+    fn limbs(&self) -> &[u64];
+
+    /// Parses exactly `BYTES` big-endian bytes, rejecting values ≥ n.
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self>;
+    /// Parses `BYTES` big-endian bytes holding a value below 2n, e.g. an
+    /// x coordinate or a truncated digest, and reduces it modulo n.
+    fn from_be_bytes_reduced(bytes: &[u8]) -> Self;
+    fn to_be_bytes(&self) -> Vec<u8>;
+
+    /// Bit `i` of the canonical value, counting from the least significant.
+    fn bit(&self, i: usize) -> bool {
+        (self.limbs()[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// Uniformly random scalar in `[0, n)`, by rejection sampling.
+    fn random(rng: &mut impl RngCore) -> Self {
+        let mut bytes = vec![0u8; Self::BYTES];
+        let excess = Self::BYTES * 8 - Self::NUM_BITS;
+        loop {
+            rng.fill_bytes(&mut bytes);
+            bytes[0] &= 0xff >> excess;
+            if let Some(scalar) = Self::from_be_bytes(&bytes) {
+                return scalar;
+            }
+        }
+    }
+
+    /// Inverts many scalars at once with Montgomery's trick: one inversion
+    /// plus three multiplications per scalar. Zero entries come back as zero.
+    fn batch_invert(scalars: &[Self]) -> Vec<Self> {
+        let mut prefix = Vec::with_capacity(scalars.len());
+        let mut acc = Self::ONE;
+        for scalar in scalars {
+            prefix.push(acc);
+            if !scalar.is_zero() {
+                acc = acc * *scalar;
+            }
+        }
+        let mut inv = acc.invert().expect("product of non-zero scalars");
+        let mut out = vec![Self::ZERO; scalars.len()];
+        for (i, scalar) in scalars.iter().enumerate().rev() {
+            if scalar.is_zero() {
+                continue;
+            }
+            out[i] = inv * prefix[i];
+            inv = inv * *scalar;
+        }
+        out
+    }
+
+    /// Converts a `BigInt` in `[0, n)`; anything else gives `None`.
+    fn from_bigint(value: &BigInt) -> Option<Self> {
+        from_bigint_with(value, Self::BYTES, Self::from_be_bytes)
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &self.to_be_bytes())
+    }
+}
+
+fn from_bigint_with<T>(value: &BigInt, len: usize, parse: impl Fn(&[u8]) -> Option<T>) -> Option<T> {
+    if value.is_negative() || value.bits() > len as u64 * 8 {
+        return None;
+    }
+    let (_, bytes) = value.to_bytes_be();
+    let mut padded = vec![0u8; len];
+    padded[len - bytes.len()..].copy_from_slice(&bytes);
+    parse(&padded)
+}
+
+/// A short Weierstrass curve y² = x³ + ax + b over GF(p) with a generator G
+/// of prime order n and cofactor h. Implemented by the parameter sets in
+/// `secp256k1` (secp256k1, P-256, P-384 and P-521); the point types and
+/// ECDSA are generic over it.
+///
+/// The scalar multiplication hooks have generic defaults. A curve can
+/// override them with faster specialised code, as secp256k1 does with its
+/// precomputed generator table and the GLV endomorphism.
+pub trait CurveParams: Debug + Sized + Send + Sync + 'static {
+    type Field: Field;
+    type Scalar: ScalarField;
+    /// The hash `sign_message` and `validate` apply to messages, and the
+    /// HMAC hash for RFC 6979 nonces of prehashed signatures.
+    type MessageDigest: Digest + BlockSizeUser;
+
+    /// The shared parameter set.
+    fn get() -> &'static Self;
+    fn p(&self) -> &BigInt;
+    fn a(&self) -> Self::Field;
+    fn b(&self) -> Self::Field;
+    fn g(&self) -> &AffinePoint<Self>;
+    fn n(&self) -> &BigInt;
+    fn h(&self) -> u64;
+
+    /// k·G for a secret k. Defaults to the constant-time ladder on the
+    /// complete formulas of `HomogeneousPoint`.
+    fn mul_generator(k: &Self::Scalar) -> ProjectivePoint<Self> {
+        HomogeneousPoint::from(Self::get().g()).times_ct(k).to_jacobian()
+    }
+
+    /// k·P for a public k. Variable time.
+    fn mul(p: &AffinePoint<Self>, k: &Self::Scalar) -> ProjectivePoint<Self> {
+        ProjectivePoint::from(p).times(k)
+    }
+
+    /// u·G + v·P for public scalars, with interleaved wNAF. Variable time.
+    fn mul_add(u: &Self::Scalar, p: &AffinePoint<Self>, v: &Self::Scalar) -> ProjectivePoint<Self> {
+        let g_table = OddMultiples::Jacobian(wnaf::odd_multiples(Self::get().g(), wnaf::POINT_WINDOW));
+        let p_table = OddMultiples::Jacobian(wnaf::odd_multiples(p, wnaf::POINT_WINDOW));
+        wnaf::strauss(&[
+            (wnaf::wnaf(u, wnaf::POINT_WINDOW), g_table),
+            (wnaf::wnaf(v, wnaf::POINT_WINDOW), p_table),
+        ])
+    }
+}
@@ -0,0 +1,84 @@
+use num_bigint::{BigInt, Sign};
+
+use crate::error::DerError;
+
+const SEQUENCE_TAG: u8 = 0x30;
+const INTEGER_TAG: u8 = 0x02;
+
+/// Encodes `(r, s)` as `SEQUENCE { INTEGER r, INTEGER s }`. Both values are
+/// expected to be positive. Contents of 128 bytes or more (P-521) take the
+/// long-form length `0x81 len`.
+pub fn encode_signature(r: &BigInt, s: &BigInt) -> Vec<u8> {
+    let r = encode_integer(r);
+    let s = encode_integer(s);
+    let len = r.len() + s.len();
+    let mut out = Vec::with_capacity(3 + len);
+    out.push(SEQUENCE_TAG);
+    if len >= 0x80 {
+        out.push(0x81);
+    }
+    out.push(len as u8);
+    out.extend(r);
+    out.extend(s);
+    out
+}
+
+/// Parses a DER signature with the strictness rules of BIP66: minimal
+/// lengths, minimal positive integers and no trailing bytes. `scalar_bytes`
+/// is the byte length of n and bounds the size of the whole signature; only
+/// a sequence that needs it may use the one-byte long-form length.
+pub fn decode_signature(bytes: &[u8], scalar_bytes: usize) -> Result<(BigInt, BigInt), DerError> {
+    let max_content = 2 * (3 + scalar_bytes);
+    let max_len = max_content + if max_content >= 0x80 { 3 } else { 2 };
+    if bytes.len() < 8 || bytes.len() > max_len || bytes[0] != SEQUENCE_TAG {
+        return Err(DerError::InvalidSequence);
+    }
+    let (seq_len, header) = match bytes[1] {
+        0x81 if bytes[2] >= 0x80 => (bytes[2] as usize, 3),
+        len if len < 0x80 => (len as usize, 2),
+        _ => return Err(DerError::InvalidLength),
+    };
+    if seq_len + header > bytes.len() {
+        return Err(DerError::InvalidLength);
+    }
+    if seq_len + header < bytes.len() {
+        return Err(DerError::TrailingData);
+    }
+    let (r, rest) = decode_integer(&bytes[header..])?;
+    let (s, rest) = decode_integer(rest)?;
+    if !rest.is_empty() {
+        return Err(DerError::InvalidLength);
+    }
+    Ok((r, s))
+}
+
+fn encode_integer(value: &BigInt) -> Vec<u8> {
+    let (_, mut bytes) = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0x00);
+    }
+    let mut out = vec![INTEGER_TAG, bytes.len() as u8];
+    out.extend(bytes);
+    out
+}
+
+fn decode_integer(bytes: &[u8]) -> Result<(BigInt, &[u8]), DerError> {
+    if bytes.len() < 2 || bytes[0] != INTEGER_TAG {
+        return Err(DerError::InvalidInteger);
+    }
+    let len = bytes[1] as usize;
+    if len == 0 {
+        return Err(DerError::InvalidInteger);
+    }
+    if len & 0x80 != 0 || len + 2 > bytes.len() {
+        return Err(DerError::InvalidLength);
+    }
+    let content = &bytes[2..2 + len];
+    if content[0] & 0x80 != 0 {
+        return Err(DerError::NegativeInteger);
+    }
+    if len > 1 && content[0] == 0x00 && content[1] & 0x80 == 0 {
+        return Err(DerError::NonMinimalInteger);
+    }
+    Ok((BigInt::from_bytes_be(Sign::Plus, content), &bytes[2 + len..]))
+}
@@ -1,101 +1,534 @@
-use std::ops::Mul;
-
-use num_bigint::{BigInt, RandBigInt};
-use num_traits::{One, Zero};
-use rand::thread_rng;
-
-use crate::{arithmetic::{Modular, Secp256k1Point}, secp256k1::Secp256k1Params};
-
-#[derive(Debug, Clone, Default)]
-pub struct BigInt256Bounds(pub BigInt, pub BigInt);
-
-impl BigInt256Bounds {
-    pub fn get() -> Self {
-        BigInt256Bounds(
-            BigInt::parse_bytes(
-                b"-57896044618658097711785492504343953926634992332820282019728792003956564819968", 
-                10
-            ).unwrap(),
-            BigInt::parse_bytes(
-                b"57896044618658097711785492504343953926634992332820282019728792003956564819967",
-                10
-            ).unwrap()
-        )
-    }
-}
+use std::fmt::Debug;
 
+use num_bigint::BigInt;
+use num_traits::Zero;
+use rand::{thread_rng, CryptoRng, RngCore};
+use sha2::{digest::core_api::BlockSizeUser, Digest};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{arithmetic::{AffinePoint, ProjectivePoint}, curve::{CurveParams, Field, ScalarField}, der, error::{BatchError, Error}, rfc6979::Rfc6979};
+
+/// A secret scalar in `[1, n - 1]`. The value is wiped when the key is
+/// dropped and never shows up in `Debug` output.
+pub struct PrivateKey<C: CurveParams>(pub(crate) C::Scalar);
 #[derive(Debug)]
-pub struct PrivateKey(pub BigInt);
-#[derive(Debug)]
-pub struct PublicKey(pub Secp256k1Point);
+pub struct PublicKey<C: CurveParams>(pub AffinePoint<C>);
 
-impl PrivateKey {
+impl<C: CurveParams> PrivateKey<C> {
+    /// A fresh key from the thread-local CSPRNG; see `random`.
     pub fn generate() -> Self {
-        let mut rng = thread_rng();
-        let BigInt256Bounds(lbound, ubound) = BigInt256Bounds::get();
-        let pk = rng.gen_bigint_range(&lbound, &ubound);
-        Self(pk)
+        Self::random(&mut thread_rng())
+    }
+
+    /// Samples uniformly from `[1, n - 1]`, rejecting draws of zero or ≥ n.
+    pub fn random(rng: &mut (impl CryptoRng + RngCore)) -> Self {
+        loop {
+            let pk = C::Scalar::random(rng);
+            if !pk.is_zero() {
+                return Self(pk);
+            }
+        }
+    }
+
+    /// Parses a big-endian secret, which must lie in `[1, n - 1]`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match C::Scalar::from_be_bytes(bytes) {
+            Some(pk) if !pk.is_zero() => Ok(Self(pk)),
+            _ => Err(Error::InvalidScalar),
+        }
+    }
+
+    /// The big-endian secret, wiped when the returned buffer is dropped.
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.0.to_be_bytes())
     }
 }
 
-impl PublicKey {
-    pub fn new(private_key: &PrivateKey) -> Self {
-        let g = Secp256k1Params::get().g;
+impl<C: CurveParams> PublicKey<C> {
+    pub fn new(private_key: &PrivateKey<C>) -> Result<Self, Error> {
         let PrivateKey(priv_key) = private_key;
-        let pub_key = g.times(priv_key);
-        PublicKey(pub_key)
+        if priv_key.is_zero() {
+            return Err(Error::InvalidScalar);
+        }
+        let pub_key = C::mul_generator(priv_key).to_affine();
+        if pub_key.is_zero() {
+            return Err(Error::PointAtInfinity);
+        }
+        Ok(PublicKey(pub_key))
+    }
+
+    /// SEC1 (section 2.3.3) encoding: `0x02`/`0x03` followed by x for the
+    /// compressed form, `0x04 || x || y` otherwise.
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        let (x, y) = match (&self.0.x, &self.0.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return vec![0x00],
+        };
+        let mut bytes = Vec::with_capacity(1 + 2 * C::Field::BYTES);
+        if compressed {
+            bytes.push(if y.is_odd() { 0x03 } else { 0x02 });
+            bytes.extend(x.to_be_bytes());
+        } else {
+            bytes.push(0x04);
+            bytes.extend(x.to_be_bytes());
+            bytes.extend(y.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Parses a SEC1 encoded point (section 2.3.4). The identity and points
+    /// off the curve are rejected.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let len = C::Field::BYTES;
+        let coordinate = |b: &[u8]| C::Field::from_be_bytes(b).ok_or(Error::PointNotOnCurve);
+        match (bytes.first(), bytes.len()) {
+            (Some(0x00), 1) => Err(Error::PointAtInfinity),
+            (Some(prefix @ (0x02 | 0x03)), l) if l == 1 + len => {
+                let x = coordinate(&bytes[1..])?;
+                let point = AffinePoint::from_x(x, *prefix == 0x03)?;
+                Ok(PublicKey(point))
+            }
+            (Some(0x04), l) if l == 1 + 2 * len => {
+                let x = coordinate(&bytes[1..1 + len])?;
+                let y = coordinate(&bytes[1 + len..])?;
+                Ok(PublicKey(AffinePoint::new(x, y)?))
+            }
+            _ => Err(Error::InvalidEncoding),
+        }
     }
 }
 
-impl PartialEq for PrivateKey {
+impl<C: CurveParams> PartialEq for PublicKey<C> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
+impl<C: CurveParams> ConstantTimeEq for PrivateKey<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<C: CurveParams> PartialEq for PrivateKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: CurveParams> Eq for PrivateKey<C> {}
+
+impl<C: CurveParams> Debug for PrivateKey<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PrivateKey(<redacted>)")
+    }
+}
+
+impl<C: CurveParams> Drop for PrivateKey<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<C: CurveParams> ZeroizeOnDrop for PrivateKey<C> {}
+
 #[derive(Debug)]
-pub struct Signature {
-    pub r: BigInt,
-    pub s: BigInt
+pub struct Signature<C: CurveParams> {
+    pub r: C::Scalar,
+    pub s: C::Scalar
 }
 
-impl Signature {
-    pub fn sign_message(message: &str, private_key: &PrivateKey) -> (Self, BigInt) {
-        let Secp256k1Params{
-            a: _, 
-            b: _, 
-            p: _, 
-            g, 
-            n
-        } = Secp256k1Params::get();
-        let (r, gen_k) = loop {
-            let mut rng = thread_rng();
-            let (lbound, ubound) = (One::one(), n.clone());
-            let gen_k_temp = rng.gen_bigint_range(&lbound, &ubound);
-            let R = g.times(&gen_k_temp);
-            let r_temp = (R.x.unwrap()).modulus(&n);
-            if r_temp.ne(&Zero::zero()) {
-                break (r_temp, gen_k_temp);
+impl<C: CurveParams> Signature<C> {
+    /// Signs the digest of `message` (`CurveParams::MessageDigest`, SHA-256
+    /// for secp256k1) with a deterministic RFC 6979 nonce, so the same key
+    /// and message always give the same signature.
+    pub fn sign_message(message: &str, private_key: &PrivateKey<C>) -> Result<Self, Error> {
+        Self::sign_digest(C::MessageDigest::new_with_prefix(message), private_key)
+    }
+
+    /// Same as `sign_message`, but mixes `extra_entropy` into the nonce
+    /// derivation as described in RFC 6979, section 3.6.
+    pub fn sign_message_with_entropy(message: &str, private_key: &PrivateKey<C>, extra_entropy: &[u8]) -> Result<Self, Error> {
+        let hash = C::MessageDigest::digest(message);
+        Self::sign_hash::<C::MessageDigest>(&hash, private_key, Some(extra_entropy)).map(Self::from)
+    }
+
+    /// Signs whatever has been fed into `digest`. The same hash function drives
+    /// the RFC 6979 HMAC.
+    pub fn sign_digest<D: Digest + BlockSizeUser>(digest: D, private_key: &PrivateKey<C>) -> Result<Self, Error> {
+        let hash = digest.finalize();
+        Self::sign_hash::<D>(&hash, private_key, None).map(Self::from)
+    }
+
+    /// Signs a digest the caller has already computed. Digests longer than
+    /// n are truncated to its bit length; see `hash_to_scalar`.
+    pub fn sign_prehashed(hash: &[u8], private_key: &PrivateKey<C>) -> Result<Self, Error> {
+        Self::sign_hash::<C::MessageDigest>(hash, private_key, None).map(Self::from)
+    }
+
+    /// Produces the recoverable form; the plain signers drop the recovery id.
+    /// s is always normalized to the lower half, negating R's parity with it.
+    fn sign_hash<D: Digest + BlockSizeUser>(hash: &[u8], private_key: &PrivateKey<C>, extra_entropy: Option<&[u8]>) -> Result<RecoverableSignature<C>, Error> {
+        let n = C::get().n();
+        let x = &private_key.0;
+        if x.is_zero() {
+            return Err(Error::InvalidScalar);
+        }
+        let e = hash_to_scalar::<C>(hash);
+        let mut nonces = Rfc6979::<D>::new(n, &private_key.to_bytes(), hash, extra_entropy);
+        loop {
+            let mut gen_k = C::Scalar::from_be_bytes(&nonces.next_k())
+                .expect("RFC 6979 nonces lie in [1, n - 1]");
+            let big_r = C::mul_generator(&gen_k).to_affine();
+            // k is never zero, so neither is k⁻¹; a zero here would only
+            // make s zero and the nonce rejected below.
+            let mut inv_k = gen_k.invert().unwrap_or(C::Scalar::ZERO);
+            gen_k.zeroize();
+            let (r_x, r_y) = match (big_r.x, big_r.y) {
+                (Some(r_x), Some(r_y)) => (r_x, r_y),
+                _ => {
+                    inv_k.zeroize();
+                    continue;
+                }
+            };
+            let r = C::Scalar::from_be_bytes_reduced(&r_x.to_be_bytes());
+            let s = (e + r * *x) * inv_k;
+            inv_k.zeroize();
+            if !r.is_zero() && !s.is_zero() {
+                let overflowed = C::Scalar::from_be_bytes(&r_x.to_be_bytes()).is_none();
+                let y_is_odd = r_y.is_odd() != s.is_high();
+                let recid = y_is_odd as u8 | (overflowed as u8) << 1;
+                let s = if s.is_high() { -s } else { s };
+                return Ok(RecoverableSignature { r, s, recid });
             }
+        }
+    }
+
+    /// Checks `signature` against the digest of `message`, hashed as in
+    /// `sign_message`. Returns `Error::InvalidSignature` when it does not verify.
+    pub fn validate(message: &str, public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        Self::verify_digest(C::MessageDigest::new_with_prefix(message), public_key, signature)
+    }
+
+    pub fn verify_digest<D: Digest>(digest: D, public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        let hash = digest.finalize();
+        Self::verify_hash(&hash, public_key, signature)
+    }
+
+    pub fn verify_prehashed(hash: &[u8], public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        Self::verify_hash(hash, public_key, signature)
+    }
+
+    /// Like `validate`, but also rejects high-S signatures with
+    /// `Error::HighS`, as BIP62/BIP146 and EIP-2 require.
+    pub fn validate_strict(message: &str, public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        if !signature.is_low_s() {
+            return Err(Error::HighS);
+        }
+        Self::validate(message, public_key, signature)
+    }
+
+    /// Like `verify_prehashed`, but also rejects high-S signatures.
+    pub fn verify_prehashed_strict(hash: &[u8], public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        if !signature.is_low_s() {
+            return Err(Error::HighS);
+        }
+        Self::verify_hash(hash, public_key, signature)
+    }
+
+    /// Whether s ≤ n / 2. (r, s) and (r, n - s) both verify, so only the low
+    /// form is accepted as canonical.
+    pub fn is_low_s(&self) -> bool {
+        !self.s.is_high()
+    }
+
+    /// The low-S form of this signature: s is replaced by n - s when above n / 2.
+    pub fn normalize_s(&self) -> Self {
+        let s = if self.s.is_high() { -self.s } else { self.s };
+        Self { r: self.r, s }
+    }
+
+    fn verify_hash(hash: &[u8], public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<(), Error> {
+        let signature = Self::check_inputs(public_key, signature)?;
+        let inv_s = signature.s.invert().ok_or(Error::InvalidScalar)?;
+        signature.check_equation(&hash_to_scalar::<C>(hash), public_key, &inv_s)
+    }
+
+    /// Verifies many `(message, public key, signature)` triples one by one,
+    /// e.g. every signature in a block, and reports the first entry that
+    /// fails, in index order. This is not a batch check; for one random
+    /// linear combination in a single multi-scalar multiplication, sign with
+    /// `RecoverableSignature` and use its `verify_batch_with_rng`.
+    ///
+    /// Plain (r, s) signatures cannot be folded into such a combination: r
+    /// only pins down R.x, not the sign of R, so Σ aᵢ·(uᵢ·G + vᵢ·Pᵢ - Rᵢ)
+    /// cannot be formed without guessing 2^k sign patterns. What does
+    /// amortize is shared: all the s⁻¹ come from a single scalar inversion,
+    /// and no field inversion is done at all.
+    pub fn verify_each_batch_inverted(items: &[(&str, &PublicKey<C>, &Signature<C>)]) -> Result<(), BatchError> {
+        let checked: Vec<Result<Self, Error>> = items
+            .iter()
+            .map(|(_, public_key, signature)| Self::check_inputs(public_key, signature))
+            .collect();
+        // Entries that failed their input checks take s = 0, which
+        // `batch_invert` skips.
+        let s_values: Vec<C::Scalar> = checked
+            .iter()
+            .map(|signature| signature.as_ref().map_or(C::Scalar::ZERO, |sig| sig.s))
+            .collect();
+        let inverses = C::Scalar::batch_invert(&s_values);
+        for (index, ((message, public_key, _), signature)) in items.iter().zip(checked).enumerate() {
+            let e = hash_to_scalar::<C>(&C::MessageDigest::digest(message));
+            signature
+                .and_then(|signature| signature.check_equation(&e, public_key, &inverses[index]))
+                .map_err(|error| BatchError { index, error })?;
+        }
+        Ok(())
+    }
+
+    /// Range checks on r and s, and rejects public keys that are not proper
+    /// curve points.
+    fn check_inputs(public_key: &PublicKey<C>, signature: &Signature<C>) -> Result<Self, Error> {
+        let signature = Self::from_scalars(signature.r, signature.s)?;
+        if public_key.0.is_zero() {
+            return Err(Error::PointAtInfinity);
+        }
+        if !public_key.0.is_on_curve() {
+            return Err(Error::PointNotOnCurve);
+        }
+        Ok(signature)
+    }
+
+    /// R' = u·G + v·P stays in Jacobian coordinates. Its x is compared with r
+    /// and, in case R.x was reduced, with r + n when that is still below p.
+    fn check_equation(&self, e: &C::Scalar, public_key: &PublicKey<C>, inv_s: &C::Scalar) -> Result<(), Error> {
+        let u = *e * *inv_s;
+        let v = self.r * *inv_s;
+        let c = ProjectivePoint::mul_add(&u, &public_key.0, &v);
+        let r = self.r.to_bigint();
+        if matches!(C::Field::from_bigint(&r), Some(x) if c.has_affine_x(&x)) {
+            return Ok(());
+        }
+        match C::Field::from_bigint(&(r + C::get().n())) {
+            Some(x) if c.has_affine_x(&x) => Ok(()),
+            _ => Err(Error::InvalidSignature),
+        }
+    }
+
+    /// Strict DER encoding: `SEQUENCE { INTEGER r, INTEGER s }`.
+    pub fn to_der(&self) -> Vec<u8> {
+        der::encode_signature(&self.r.to_bigint(), &self.s.to_bigint())
+    }
+
+    /// Parses a DER signature under BIP66 rules. `r` and `s` must also lie in `[1, n - 1]`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let (r, s) = der::decode_signature(bytes, C::Scalar::BYTES)?;
+        let scalar = |v: BigInt| C::Scalar::from_bigint(&v).ok_or(Error::InvalidScalar);
+        Self::from_scalars(scalar(r)?, scalar(s)?)
+    }
+
+    /// Fixed-size `r || s` encoding (64 bytes for secp256k1), each half big-endian.
+    pub fn to_compact(&self) -> Vec<u8> {
+        let mut bytes = self.r.to_be_bytes();
+        bytes.extend(self.s.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_compact(bytes: &[u8]) -> Result<Self, Error> {
+        let len = C::Scalar::BYTES;
+        if bytes.len() != 2 * len {
+            return Err(Error::InvalidEncoding);
+        }
+        let scalar = |b: &[u8]| C::Scalar::from_be_bytes(b).ok_or(Error::InvalidScalar);
+        Self::from_scalars(scalar(&bytes[..len])?, scalar(&bytes[len..])?)
+    }
+
+    /// `r` and `s` are already below n by construction; zero is still rejected.
+    fn from_scalars(r: C::Scalar, s: C::Scalar) -> Result<Self, Error> {
+        if r.is_zero() || s.is_zero() {
+            return Err(Error::InvalidScalar);
+        }
+        Ok(Self { r, s })
+    }
+}
+
+/// An ECDSA signature together with the recovery id that pins down R among
+/// the (up to four) points whose x coordinate reduces to r: bit 0 is the
+/// parity of R.y, bit 1 is set when R.x was at least n.
+#[derive(Debug)]
+pub struct RecoverableSignature<C: CurveParams> {
+    pub r: C::Scalar,
+    pub s: C::Scalar,
+    pub recid: u8,
+}
+
+impl<C: CurveParams> RecoverableSignature<C> {
+    /// Same as `Signature::sign_message`, keeping the recovery id.
+    pub fn sign_message(message: &str, private_key: &PrivateKey<C>) -> Result<Self, Error> {
+        let hash = C::MessageDigest::digest(message);
+        Signature::sign_hash::<C::MessageDigest>(&hash, private_key, None)
+    }
+
+    /// Same as `Signature::sign_prehashed`, keeping the recovery id.
+    pub fn sign_prehashed(hash: &[u8], private_key: &PrivateKey<C>) -> Result<Self, Error> {
+        Signature::sign_hash::<C::MessageDigest>(hash, private_key, None)
+    }
+
+    /// `r || s || v` with v = recid, the layout Ethereum's `ecrecover` takes
+    /// (add 27 for the legacy v). Bitcoin signed messages put a header byte
+    /// of 27 + recid (+ 4 for a compressed key) in front of `r || s` instead.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Signature::<C> { r: self.r, s: self.s }.to_compact();
+        bytes.push(self.recid);
+        bytes
+    }
+
+    /// Parses `r || s || v`. Both v = recid and the legacy v = 27 + recid are
+    /// accepted.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let Some((&v, compact)) = bytes.split_last() else {
+            return Err(Error::InvalidEncoding);
+        };
+        let Signature { r, s } = Signature::<C>::from_compact(compact)?;
+        let recid = match v {
+            v @ 0..=3 => v,
+            v @ 27..=30 => v - 27,
+            _ => return Err(Error::InvalidRecoveryId),
         };
+        Ok(Self { r, s, recid })
+    }
+
+    /// Verifies many `(message, public key, signature)` triples with one
+    /// random linear combination, using weights from the thread-local
+    /// CSPRNG; see `verify_batch_with_rng`.
+    pub fn verify_batch(items: &[(&str, &PublicKey<C>, &RecoverableSignature<C>)]) -> Result<(), BatchError> {
+        Self::verify_batch_with_rng(items, &mut thread_rng())
+    }
 
-        let msg_as_bigint = BigInt::from_signed_bytes_be(message.as_bytes());
-         let s= ((msg_as_bigint + r.clone().mul(&private_key.0)) * gen_k.invmod(&n)
-            .unwrap()).modulus(&n);
-        (Self { r, s }, gen_k)
-    }
-
-    pub fn validate(message: &str, public_key: &PublicKey, signature: &Signature) -> bool {
-        let Secp256k1Params{a: _, b: _, p: _, g, n} = Secp256k1Params::get();
-        let msg_as_bigint = BigInt::from_signed_bytes_be(message.as_bytes());
-        let inv_s = (&signature.s).invmod(&n).unwrap();
-        let u = (msg_as_bigint.mul(&inv_s)).modulus(&n);
-        let v = (&signature.r).mul(&inv_s).modulus(&n);
-        let c = g.times(&u) + public_key.0.times(&v);
-        if let (Some(c_x), Some(_)) = (c.x, c.y) {
-            c_x.modulus(&n).eq(&signature.r)
+    /// The recovery id fixes R, so each equation can be taken in the form
+    /// sᵢ·Rᵢ = eᵢ·G + rᵢ·Pᵢ, which needs no inversion. With a₁ = 1 and
+    /// random weights aᵢ in `[1, n - 1]` the batch checks
+    ///
+    ///   (Σ aᵢ·eᵢ)·G + Σ (aᵢ·rᵢ)·Pᵢ - Σ (aᵢ·sᵢ)·Rᵢ = 0
+    ///
+    /// in a single multi-scalar multiplication (`AffinePoint::lincomb`,
+    /// Pippenger for large batches). A bad entry passes only if the weights
+    /// happen to cancel its error, which has negligible probability. The
+    /// weights must not be predictable to whoever picked the signatures.
+    ///
+    /// Besides the ECDSA equation, R must have the parity the recovery id
+    /// claims, as `recover_public_key` relies on it. When the combined check
+    /// fails, or an entry is malformed, the entries are verified one by one
+    /// and the first failure is reported.
+    pub fn verify_batch_with_rng(items: &[(&str, &PublicKey<C>, &RecoverableSignature<C>)], rng: &mut (impl CryptoRng + RngCore)) -> Result<(), BatchError> {
+        let mut terms = Vec::with_capacity(2 * items.len() + 1);
+        let mut e_sum = C::Scalar::ZERO;
+        for (index, (message, public_key, signature)) in items.iter().enumerate() {
+            let checked = Signature::check_inputs(public_key, &Signature { r: signature.r, s: signature.s })
+                .and_then(|_| signature.big_r());
+            let Ok(big_r) = checked else {
+                return Self::verify_each(items);
+            };
+            let e = hash_to_scalar::<C>(&C::MessageDigest::digest(message));
+            let a = if index == 0 { C::Scalar::ONE } else { random_weight(rng) };
+            e_sum = e_sum + a * e;
+            terms.push((a * signature.r, public_key.0.clone()));
+            terms.push((-(a * signature.s), big_r));
+        }
+        terms.push((e_sum, C::get().g().clone()));
+        if AffinePoint::lincomb(&terms).is_zero() {
+            return Ok(());
+        }
+        Self::verify_each(items)
+    }
+
+    /// Finds the first entry that fails on its own. Should all of them pass,
+    /// their combination holds too, so the batch is valid after all.
+    fn verify_each(items: &[(&str, &PublicKey<C>, &RecoverableSignature<C>)]) -> Result<(), BatchError> {
+        for (index, (message, public_key, signature)) in items.iter().enumerate() {
+            let hash = C::MessageDigest::digest(message);
+            signature.verify_hash(&hash, public_key).map_err(|error| BatchError { index, error })?;
+        }
+        Ok(())
+    }
+
+    /// Checks u·G + v·P = R exactly, y included, with R rebuilt from r and
+    /// the recovery id.
+    fn verify_hash(&self, hash: &[u8], public_key: &PublicKey<C>) -> Result<(), Error> {
+        let signature = Signature::check_inputs(public_key, &Signature { r: self.r, s: self.s })?;
+        let big_r = self.big_r()?;
+        let inv_s = signature.s.invert().ok_or(Error::InvalidScalar)?;
+        let e = hash_to_scalar::<C>(hash);
+        let c = ProjectivePoint::mul_add(&(e * inv_s), &public_key.0, &(signature.r * inv_s));
+        if c == ProjectivePoint::from(&big_r) {
+            Ok(())
         } else {
-            false
+            Err(Error::InvalidSignature)
+        }
+    }
+
+    /// R, the point whose x reduces to r, with R.x = r + n when bit 1 of the
+    /// recovery id is set and the parity of R.y given by bit 0.
+    fn big_r(&self) -> Result<AffinePoint<C>, Error> {
+        if self.recid > 3 {
+            return Err(Error::InvalidRecoveryId);
+        }
+        let mut r_x = self.r.to_bigint();
+        if self.recid & 2 != 0 {
+            r_x += C::get().n();
+        }
+        let r_x = C::Field::from_bigint(&r_x).ok_or(Error::InvalidRecoveryId)?;
+        AffinePoint::from_x(r_x, self.recid & 1 == 1)
+    }
+}
+
+/// A batch weight, uniform in `[1, n - 1]`.
+pub(crate) fn random_weight<S: ScalarField>(rng: &mut (impl CryptoRng + RngCore)) -> S {
+    loop {
+        let a = S::random(rng);
+        if !a.is_zero() {
+            return a;
+        }
+    }
+}
+
+impl<C: CurveParams> From<RecoverableSignature<C>> for Signature<C> {
+    fn from(signature: RecoverableSignature<C>) -> Self {
+        Signature { r: signature.r, s: signature.s }
+    }
+}
+
+/// Recovers the public key that produced `signature` over `msg_hash`
+/// (SEC1, section 4.1.6): Q = r⁻¹·(s·R - e·G), with R rebuilt from r and
+/// the recovery id.
+pub fn recover_public_key<C: CurveParams>(msg_hash: &[u8], signature: &RecoverableSignature<C>) -> Result<PublicKey<C>, Error> {
+    let Signature { r, s } = Signature::<C>::from_scalars(signature.r, signature.s)?;
+    let big_r = signature.big_r()?;
+    let inv_r = r.invert().ok_or(Error::InvalidScalar)?;
+    let e = hash_to_scalar::<C>(msg_hash);
+    let q = AffinePoint::mul_add(&-(e * inv_r), &big_r, &(s * inv_r));
+    if q.is_zero() {
+        return Err(Error::PointAtInfinity);
+    }
+    Ok(PublicKey(q))
+}
+
+/// Converts a message digest into the integer `e` of SEC1, section 4.1.3:
+/// the leftmost ⌈log2 n⌉ bits of the hash, read as an unsigned big-endian
+/// number and reduced modulo n.
+pub fn hash_to_scalar<C: CurveParams>(hash: &[u8]) -> C::Scalar {
+    let len = C::Scalar::BYTES;
+    let take = hash.len().min(len);
+    let mut bytes = vec![0u8; len];
+    bytes[len - take..].copy_from_slice(&hash[..take]);
+    // Below 8 bits, since `len` is the byte length of n.
+    let shift = (take * 8).saturating_sub(C::Scalar::NUM_BITS);
+    if shift > 0 {
+        for i in (0..len).rev() {
+            let carry = if i > 0 { bytes[i - 1] << (8 - shift) } else { 0 };
+            bytes[i] = (bytes[i] >> shift) | carry;
         }
     }
+    C::Scalar::from_be_bytes_reduced(&bytes)
 }
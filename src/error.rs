@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The coordinates (or the lone x coordinate) do not describe a point on the curve.
+    PointNotOnCurve,
+    /// The point at infinity was given where a proper curve point is required.
+    PointAtInfinity,
+    /// The byte string does not follow the expected encoding.
+    InvalidEncoding,
+    /// A scalar (such as `r` or `s`) is outside of `[1, n - 1]`.
+    InvalidScalar,
+    /// The signature does not verify against the message and public key.
+    InvalidSignature,
+    /// `s` is above n / 2, which strict verification (BIP62/BIP146, EIP-2) rejects.
+    HighS,
+    /// A recovery id outside of `0..=3`, or one that puts R.x at or beyond p.
+    InvalidRecoveryId,
+    /// The DER signature encoding is malformed.
+    Der(DerError),
+}
+
+/// Reasons a strict (BIP66) DER signature is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DerError {
+    /// Missing SEQUENCE tag, or the input is shorter or longer than any valid signature.
+    InvalidSequence,
+    /// A length byte disagrees with the data that follows it.
+    InvalidLength,
+    /// Missing INTEGER tag, or an INTEGER with no content bytes.
+    InvalidInteger,
+    /// An INTEGER has its sign bit set.
+    NegativeInteger,
+    /// An INTEGER carries an unnecessary leading zero byte.
+    NonMinimalInteger,
+    /// Bytes follow the end of the SEQUENCE.
+    TrailingData,
+}
+
+/// A batch of signatures failed to verify.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchError {
+    /// Position of the first offending entry.
+    pub index: usize,
+    /// Why that entry was rejected.
+    pub error: Error,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::PointNotOnCurve => write!(f, "point is not on the curve"),
+            Error::PointAtInfinity => write!(f, "point at infinity"),
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+            Error::InvalidScalar => write!(f, "scalar is out of range"),
+            Error::InvalidSignature => write!(f, "signature verification failed"),
+            Error::HighS => write!(f, "s is not in the lower half of the group order"),
+            Error::InvalidRecoveryId => write!(f, "recovery id is out of range"),
+            Error::Der(err) => write!(f, "invalid DER signature: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "batch entry {}: {}", self.index, self.error)
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+impl Display for DerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DerError::InvalidSequence => write!(f, "not a SEQUENCE of the expected size"),
+            DerError::InvalidLength => write!(f, "length does not match content"),
+            DerError::InvalidInteger => write!(f, "expected a non-empty INTEGER"),
+            DerError::NegativeInteger => write!(f, "negative INTEGER"),
+            DerError::NonMinimalInteger => write!(f, "INTEGER is not minimally encoded"),
+            DerError::TrailingData => write!(f, "trailing data after SEQUENCE"),
+        }
+    }
+}
+
+impl From<DerError> for Error {
+    fn from(err: DerError) -> Self {
+        Error::Der(err)
+    }
+}
@@ -0,0 +1,306 @@
+use std::{fmt::Display, ops::{Add, Mul, Neg, Sub}};
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Signed;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::curve::Field;
+
+/// p = 2^256 - 2^32 - 977, least significant limb first.
+const P: [u64; 4] = [0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+/// p - 2, the inversion exponent.
+const P_MINUS_2: [u64; 4] = [0xFFFFFFFEFFFFFC2D, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+/// (p + 1) / 4, the square root exponent (p ≡ 3 mod 4).
+const P_PLUS_1_DIV_4: [u64; 4] = [0xFFFFFFFFBFFFFF0C, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x3FFFFFFFFFFFFFFF];
+/// 2^256 mod p = 2^32 + 977, the folding constant of the special-form reduction.
+const R: u64 = 0x1000003D1;
+
+/// Element of the secp256k1 base field stored as four 64-bit limbs, least
+/// significant first. Values are always fully reduced, so limb equality is
+/// field equality. Arithmetic does not branch on the limb values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldElement([u64; 4]);
+
+impl FieldElement {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    pub const fn from_u64(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+
+    /// Little-endian limbs, which must already be below p.
+    #[cfg(feature = "glv")]
+    pub(crate) const fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self(limbs)
+    }
+
+    /// Parses a big-endian encoding, rejecting values ≥ p.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 24 - 8 * i;
+            *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().unwrap());
+        }
+        let (_, borrow) = sub_limbs(&limbs, &P);
+        if borrow == 1 {
+            Some(Self(limbs))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            let start = 24 - 8 * i;
+            bytes[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Converts a `BigInt` in `[0, p)`; anything else gives `None`.
+    pub fn from_bigint(value: &BigInt) -> Option<Self> {
+        if value.is_negative() || value.bits() > 256 {
+            return None;
+        }
+        let (_, bytes) = value.to_bytes_be();
+        let mut padded = [0u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        Self::from_bytes(&padded)
+    }
+
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &self.to_bytes())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO).into()
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// Raises `self` to a public exponent. The running time depends on the
+    /// exponent only, never on `self`.
+    pub fn pow(&self, exp: &[u64; 4]) -> Self {
+        let mut res = Self::ONE;
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                res = res.square();
+                if (limb >> bit) & 1 == 1 {
+                    res = res * *self;
+                }
+            }
+        }
+        res
+    }
+
+    /// Multiplicative inverse via Fermat's little theorem, a^(p-2).
+    pub fn invert(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&P_MINUS_2))
+    }
+
+    /// Square root a^((p+1)/4), or `None` when `self` is not a square.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(&P_PLUS_1_DIV_4);
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// `value = carry·2^256 + limbs`, assumed below 2p; subtracts p once if needed.
+    fn reduce_once(limbs: [u64; 4], carry: u64) -> Self {
+        let (diff, borrow) = sub_limbs(&limbs, &P);
+        let use_diff = Choice::from((carry | (borrow ^ 1)) as u8);
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&limbs[i], &diff[i], use_diff);
+        }
+        Self(out)
+    }
+
+    /// Adds p back after a subtraction that borrowed (`borrow == 1`).
+    fn add_p_masked(diff: [u64; 4], borrow: u64) -> Self {
+        let mask = u64::conditional_select(&0, &u64::MAX, Choice::from(borrow as u8));
+        let mut out = [0u64; 4];
+        let mut carry = 0;
+        for (i, limb) in out.iter_mut().enumerate() {
+            (*limb, carry) = adc(diff[i], P[i] & mask, carry);
+        }
+        Self(out)
+    }
+
+    /// Reduces a 512-bit product using 2^256 ≡ 2^32 + 977 (mod p).
+    fn reduce_wide(t: [u64; 8]) -> Self {
+        let mut r = [0u64; 4];
+        let mut carry = 0;
+        for i in 0..4 {
+            (r[i], carry) = mac(t[i], t[i + 4], R, carry);
+        }
+        // carry < 2^34, fold it back in once more.
+        let mut c;
+        (r[0], c) = mac(r[0], carry, R, 0);
+        for limb in r.iter_mut().skip(1) {
+            (*limb, c) = adc(*limb, 0, c);
+        }
+        // Overflowing 2^256 here leaves r tiny, so adding R cannot carry again.
+        (r[0], c) = adc(r[0], u64::conditional_select(&0, &R, Choice::from(c as u8)), 0);
+        for limb in r.iter_mut().skip(1) {
+            (*limb, c) = adc(*limb, 0, c);
+        }
+        Self::reduce_once(r, 0)
+    }
+}
+
+impl Add for FieldElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut sum = [0u64; 4];
+        let mut carry = 0;
+        for (i, limb) in sum.iter_mut().enumerate() {
+            (*limb, carry) = adc(self.0[i], rhs.0[i], carry);
+        }
+        Self::reduce_once(sum, carry)
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = sub_limbs(&self.0, &rhs.0);
+        Self::add_p_masked(diff, borrow)
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut t = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0;
+            for j in 0..4 {
+                (t[i + j], carry) = mac(t[i + j], self.0[i], rhs.0[j], carry);
+            }
+            t[i + 4] = carry;
+        }
+        Self::reduce_wide(t)
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Self(out)
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[0].ct_eq(&other.0[0])
+            & self.0[1].ct_eq(&other.0[1])
+            & self.0[2].ct_eq(&other.0[2])
+            & self.0[3].ct_eq(&other.0[3])
+    }
+}
+
+impl Field for FieldElement {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const BYTES: usize = 32;
+
+    fn from_u64(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+
+    fn is_zero(&self) -> bool {
+        FieldElement::is_zero(self)
+    }
+
+    fn is_odd(&self) -> bool {
+        FieldElement::is_odd(self)
+    }
+
+    fn square(&self) -> Self {
+        FieldElement::square(self)
+    }
+
+    fn double(&self) -> Self {
+        FieldElement::double(self)
+    }
+
+    fn invert(&self) -> Option<Self> {
+        FieldElement::invert(self)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        FieldElement::sqrt(self)
+    }
+
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes.try_into().ok()?)
+    }
+
+    fn to_be_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Display for FieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+pub(crate) const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+/// `acc + a·b + carry`, returned as (low limb, high limb).
+pub(crate) const fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = acc as u128 + (a as u128) * (b as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+pub(crate) fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut out = [0u64; 4];
+    let mut borrow = 0;
+    for i in 0..4 {
+        (out[i], borrow) = sbb(a[i], b[i], borrow);
+    }
+    (out, borrow)
+}
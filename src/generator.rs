@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use num_traits::Zero;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::{arithmetic::{JacobianPoint, Secp256k1Point}, field::FieldElement, scalar::Scalar, secp256k1::Secp256k1Params, wnaf};
+
+/// Bits of the scalar consumed per table lookup.
+const WINDOW_BITS: usize = 4;
+const WINDOWS: usize = 256 / WINDOW_BITS;
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// Fixed-base table for k·G. Window `w` holds the affine points
+/// (j + 1)·16^w·G for j in 0..16, so k·G is a sum of 64 lookups and needs
+/// no doublings at all.
+///
+/// Storing j + 1 instead of j keeps the point at infinity out of the table;
+/// the surplus Σ 16^w·G is subtracted once at the end.
+///
+/// The odd multiples G, 3G, ..., 127G used by the wNAF code in
+/// `Secp256k1Point::mul_add` live here as well.
+pub struct GeneratorTable {
+    windows: Vec<[(FieldElement, FieldElement); WINDOW_SIZE]>,
+    neg_offset: (FieldElement, FieldElement),
+    odd_multiples: Vec<Secp256k1Point>,
+}
+
+impl GeneratorTable {
+    /// The shared table, built on first use (about 1024 point additions and
+    /// a single field inversion).
+    pub fn get() -> &'static Self {
+        static TABLE: OnceLock<GeneratorTable> = OnceLock::new();
+        TABLE.get_or_init(Self::build)
+    }
+
+    fn build() -> Self {
+        let g = &Secp256k1Params::get().g;
+        let mut points = Vec::with_capacity(WINDOWS * WINDOW_SIZE);
+        let mut base = JacobianPoint::from(g);
+        let mut offset = JacobianPoint::from(g);
+        for w in 0..WINDOWS {
+            let mut multiple = base;
+            for _ in 0..WINDOW_SIZE {
+                points.push(multiple);
+                multiple = multiple + base;
+            }
+            // The last entry, 16·base, is the base of the next window.
+            base = points[points.len() - 1];
+            if w > 0 {
+                offset = offset + points[w * WINDOW_SIZE];
+            }
+        }
+        points.push(-offset);
+
+        let affine = JacobianPoint::batch_to_affine(&points);
+        let coords = |i: usize| (affine[i].x.unwrap(), affine[i].y.unwrap());
+        let windows = (0..WINDOWS)
+            .map(|w| std::array::from_fn(|j| coords(w * WINDOW_SIZE + j)))
+            .collect();
+        let odd_multiples = JacobianPoint::batch_to_affine(&wnaf::odd_multiples(g, wnaf::GENERATOR_WINDOW));
+        Self { windows, neg_offset: coords(WINDOWS * WINDOW_SIZE), odd_multiples }
+    }
+
+    pub fn odd_multiples(&self) -> &[Secp256k1Point] {
+        &self.odd_multiples
+    }
+
+    /// Computes k·G. Every window entry is scanned and picked with a masked
+    /// select, and the sums go through `add_affine_ct`, so neither the memory
+    /// access pattern nor the additions depend on k. The running sum can
+    /// equal the selected entry (k = 0x0f gives 16·G in window 1), so a
+    /// branching mixed addition would leak when it falls back to doubling.
+    pub fn mul(&self, k: &Scalar) -> JacobianPoint {
+        let limbs = k.limbs();
+        let mut acc = JacobianPoint::zero();
+        for (w, window) in self.windows.iter().enumerate() {
+            let bit = w * WINDOW_BITS;
+            let digit = (limbs[bit / 64] >> (bit % 64)) & (WINDOW_SIZE as u64 - 1);
+            let (mut x, mut y) = window[0];
+            for (j, (entry_x, entry_y)) in window.iter().enumerate().skip(1) {
+                let hit = (j as u64).ct_eq(&digit);
+                x.conditional_assign(entry_x, hit);
+                y.conditional_assign(entry_y, hit);
+            }
+            acc = acc.add_affine_ct(&x, &y);
+        }
+        acc.add_affine_ct(&self.neg_offset.0, &self.neg_offset.1)
+    }
+}
+
+/// k·G through the shared precomputed table.
+pub fn mul_generator(k: &Scalar) -> JacobianPoint {
+    GeneratorTable::get().mul(k)
+}
@@ -0,0 +1,87 @@
+use num_traits::Zero;
+
+use crate::{
+    arithmetic::{JacobianPoint, Secp256k1Point},
+    field::FieldElement,
+    generator::GeneratorTable,
+    scalar::Scalar,
+    secp256k1::Secp256k1Params,
+    wnaf::{self, OddMultiples},
+};
+
+/// λ, a cube root of unity modulo n.
+pub const LAMBDA: Scalar = Scalar::from_limbs([0xDF02967C1B23BD72, 0x122E22EA20816678, 0xA5261C028812645A, 0x5363AD4CC05C30E0]);
+/// β, the matching cube root of unity modulo p: φ(P) = λ·P.
+pub const BETA: FieldElement = FieldElement::from_limbs([0xC1396C28719501EE, 0x9CF0497512F58995, 0x6E64479EAC3434E9, 0x7AE96A2B657C0710]);
+
+// Reduced lattice basis {(a1, b1), (a2, b2)} of {(x, y) : x + yλ ≡ 0 (mod n)},
+// with b1 < 0 and b2 = a1.
+const A1: Scalar = Scalar::from_limbs([0xE86C90E49284EB15, 0x3086D221A7D46BCD, 0, 0]);
+const A2: Scalar = Scalar::from_limbs([0x57C1108D9D44CFD8, 0x14CA50F7A8E2F3F6, 0x1, 0]);
+const MINUS_B1: Scalar = Scalar::from_limbs([0x6F547FA90ABFE4C3, 0xE4437ED6010E8828, 0, 0]);
+const MINUS_B2: Scalar = Scalar::from_limbs([0xD765CDA83DB1562C, 0x8A280AC50774346D, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF]);
+// g1 = ⌊2^384·b2 / n⌉ and g2 = ⌊2^384·(-b1) / n⌉.
+const G1: [u64; 4] = [0xE893209A45DBB031, 0x3DAA8A1471E8CA7F, 0xE86C90E49284EB15, 0x3086D221A7D46BCD];
+const G2: [u64; 4] = [0x1571B4AE8AC47F71, 0x221208AC9DF506C6, 0x6F547FA90ABFE4C4, 0xE4437ED6010E8828];
+
+/// GLV decomposition (Gallant, Lambert, Vanstone, CRYPTO 2001): splits k
+/// into (k1, k2) with k ≡ k1 + k2·λ (mod n), so that k·P = k1·P + k2·φ(P).
+/// Each half is either below 2^128 or n minus such a value; see
+/// `Scalar::is_high`. Constants and lattice basis follow libsecp256k1.
+pub fn decompose(k: &Scalar) -> (Scalar, Scalar) {
+    let c1 = k.mul_shift_384(&G1);
+    let c2 = k.mul_shift_384(&G2);
+    let k2 = c1 * MINUS_B1 + c2 * MINUS_B2;
+    let k1 = *k - c1 * A1 - c2 * A2;
+    (k1, k2)
+}
+
+/// φ(P) = (βx, y) = λ·P.
+pub fn endomorphism(p: &Secp256k1Point) -> Secp256k1Point {
+    match (p.x, p.y) {
+        (Some(x), Some(y)) => Secp256k1Point::free_dot(BETA * x, y),
+        _ => Secp256k1Point::zero(),
+    }
+}
+
+/// wNAF of a GLV half. High halves are negated first so the digit string
+/// stays ~129 digits long, and the digits are flipped to compensate.
+fn half_wnaf(k: &Scalar, w: usize) -> Vec<i8> {
+    if k.is_high() {
+        wnaf::wnaf(&-*k, w).into_iter().map(|d| -d).collect()
+    } else {
+        wnaf::wnaf(k, w)
+    }
+}
+
+/// The Strauss terms for k·P: k1 against P's odd multiples and k2 against
+/// their images under φ (βX keeps the same Z).
+fn split_terms(k: &Scalar, p: &Secp256k1Point) -> [(Vec<i8>, OddMultiples<'static, Secp256k1Params>); 2] {
+    let (k1, k2) = decompose(k);
+    let table = wnaf::odd_multiples(p, wnaf::POINT_WINDOW);
+    let table_lambda = table.iter().map(|q| JacobianPoint { x: BETA * q.x, ..*q }).collect();
+    [
+        (half_wnaf(&k1, wnaf::POINT_WINDOW), OddMultiples::Jacobian(table)),
+        (half_wnaf(&k2, wnaf::POINT_WINDOW), OddMultiples::Jacobian(table_lambda)),
+    ]
+}
+
+/// k·P through the endomorphism. Variable time.
+pub fn mul(p: &Secp256k1Point, k: &Scalar) -> JacobianPoint {
+    wnaf::strauss(&split_terms(k, p))
+}
+
+/// u·G + v·P through the endomorphism: four ~128-bit terms sharing one
+/// doubling chain. Variable time.
+pub fn mul_add(u: &Scalar, p: &Secp256k1Point, v: &Scalar) -> JacobianPoint {
+    let g_table = GeneratorTable::get().odd_multiples();
+    let g_table_lambda: Vec<Secp256k1Point> = g_table.iter().map(endomorphism).collect();
+    let (u1, u2) = decompose(u);
+    let [p_term, p_term_lambda] = split_terms(v, p);
+    wnaf::strauss(&[
+        (half_wnaf(&u1, wnaf::GENERATOR_WINDOW), OddMultiples::Affine(g_table)),
+        (half_wnaf(&u2, wnaf::GENERATOR_WINDOW), OddMultiples::Affine(&g_table_lambda)),
+        p_term,
+        p_term_lambda,
+    ])
+}
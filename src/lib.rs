@@ -1,4 +1,16 @@
 pub mod secp256k1;
 pub mod arithmetic;
+pub mod curve;
+pub mod der;
 pub mod ecdsa;
+pub mod error;
+pub mod field;
+pub mod generator;
+#[cfg(feature = "glv")]
+pub mod glv;
+pub mod montgomery;
+pub mod rfc6979;
+pub mod scalar;
+pub mod schnorr;
+pub mod wnaf;
 pub mod tests;
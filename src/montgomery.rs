@@ -0,0 +1,507 @@
+use std::{fmt::{Debug, Display}, marker::PhantomData, ops::{Add, Mul, Neg, Sub}};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{curve::{Field, ScalarField}, field::{adc, mac, sbb}};
+
+/// An odd modulus of `L` 64-bit limbs, least significant limb first. The
+/// Montgomery constants are derived from it at compile time.
+pub trait Modulus<const L: usize>: Debug + Send + Sync + 'static {
+    const MODULUS: [u64; L];
+}
+
+/// Constants derived from a `Modulus`.
+trait Derived<const L: usize>: Modulus<L> {
+    /// -m⁻¹ mod 2^64.
+    const M0_INV: u64;
+    /// R = 2^(64L) mod m, i.e. one in Montgomery form.
+    const R: [u64; L];
+    /// R² mod m, which moves values into Montgomery form.
+    const R2: [u64; L];
+    /// m - 2, the inversion exponent.
+    const M_MINUS_2: [u64; L];
+    /// (m + 1) / 4, the square root exponent when m ≡ 3 (mod 4).
+    const SQRT_EXP: [u64; L];
+    /// ⌊m / 2⌋.
+    const HALF: [u64; L];
+    const NUM_BITS: usize;
+    const BYTES: usize;
+}
+
+impl<M: Modulus<L>, const L: usize> Derived<L> for M {
+    const M0_INV: u64 = m0_inv(M::MODULUS[0]);
+    const R: [u64; L] = double_times(one(), 64 * L, &M::MODULUS);
+    const R2: [u64; L] = double_times(Self::R, 64 * L, &M::MODULUS);
+    const M_MINUS_2: [u64; L] = sub_limbs(&M::MODULUS, &small(2)).0;
+    const SQRT_EXP: [u64; L] = shr(&add_one(&M::MODULUS), 2);
+    const HALF: [u64; L] = shr(&M::MODULUS, 1);
+    const NUM_BITS: usize = 64 * L - M::MODULUS[L - 1].leading_zeros() as usize;
+    const BYTES: usize = Self::NUM_BITS.div_ceil(8);
+}
+
+/// Element of GF(m) kept in Montgomery form x·R mod m, with R = 2^(64L).
+/// Used for the coordinate fields of curves without a special-form prime.
+/// Like `FieldElement`, values are always fully reduced and arithmetic does
+/// not branch on them.
+pub struct MontyField<M, const L: usize>([u64; L], PhantomData<M>);
+
+/// Integer modulo a group order. Kept in canonical form so that `limbs` can
+/// hand out the value directly; products take two Montgomery
+/// multiplications, (a·b·R⁻¹)·R²·R⁻¹ = a·b.
+pub struct MontyScalar<M, const L: usize>([u64; L], PhantomData<M>);
+
+impl<M: Modulus<L>, const L: usize> MontyField<M, L> {
+    fn from_canonical(limbs: [u64; L]) -> Self {
+        Self(mont_mul::<M, L>(&limbs, &M::R2), PhantomData)
+    }
+
+    fn to_canonical(self) -> [u64; L] {
+        mont_mul::<M, L>(&self.0, &one())
+    }
+
+    /// Raises `self` to a public exponent. The running time depends on the
+    /// exponent only, never on `self`.
+    pub fn pow(&self, exp: &[u64; L]) -> Self {
+        Self(pow::<M, L>(&self.0, exp), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> MontyScalar<M, L> {
+    /// Raises `self` to a public exponent, in the Montgomery domain.
+    pub fn pow(&self, exp: &[u64; L]) -> Self {
+        let base = mont_mul::<M, L>(&self.0, &M::R2);
+        Self(mont_mul::<M, L>(&pow::<M, L>(&base, exp), &one()), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Field for MontyField<M, L> {
+    const ZERO: Self = Self([0; L], PhantomData);
+    const ONE: Self = Self(M::R, PhantomData);
+    const BYTES: usize = M::BYTES;
+
+    fn from_u64(value: u64) -> Self {
+        Self::from_canonical(small(value))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO).into()
+    }
+
+    fn is_odd(&self) -> bool {
+        self.to_canonical()[0] & 1 == 1
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// Multiplicative inverse via Fermat's little theorem, a^(m-2).
+    fn invert(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&M::M_MINUS_2))
+    }
+
+    /// Square root a^((m+1)/4). Only correct for m ≡ 3 (mod 4), which holds
+    /// for the NIST primes.
+    fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(&M::SQRT_EXP);
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let limbs = limbs_from_be_bytes::<M, L>(bytes)?;
+        is_below_modulus::<M, L>(&limbs).then(|| Self::from_canonical(limbs))
+    }
+
+    fn to_be_bytes(&self) -> Vec<u8> {
+        limbs_to_be_bytes::<M, L>(&self.to_canonical())
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> ScalarField for MontyScalar<M, L> {
+    const ZERO: Self = Self([0; L], PhantomData);
+    const ONE: Self = Self(one(), PhantomData);
+    const BYTES: usize = M::BYTES;
+    const NUM_BITS: usize = M::NUM_BITS;
+    const MODULUS: &'static [u64] = &M::MODULUS;
+
+    fn from_u64(value: u64) -> Self {
+        Self(small(value), PhantomData)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO).into()
+    }
+
+    fn is_high(&self) -> bool {
+        let (_, borrow) = sub_limbs(&M::HALF, &self.0);
+        borrow == 1
+    }
+
+    fn invert(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&M::M_MINUS_2))
+    }
+
+    fn limbs(&self) -> &[u64] {
+        &self.0
+    }
+
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let limbs = limbs_from_be_bytes::<M, L>(bytes)?;
+        is_below_modulus::<M, L>(&limbs).then_some(Self(limbs, PhantomData))
+    }
+
+    fn from_be_bytes_reduced(bytes: &[u8]) -> Self {
+        let limbs = limbs_from_be_bytes::<M, L>(bytes).expect("BYTES long");
+        Self(reduce_once::<M, L>(limbs, 0), PhantomData)
+    }
+
+    fn to_be_bytes(&self) -> Vec<u8> {
+        limbs_to_be_bytes::<M, L>(&self.0)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Add for MontyField<M, L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(add_mod::<M, L>(&self.0, &rhs.0), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Sub for MontyField<M, L> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(sub_mod::<M, L>(&self.0, &rhs.0), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Neg for MontyField<M, L> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Mul for MontyField<M, L> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(mont_mul::<M, L>(&self.0, &rhs.0), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Add for MontyScalar<M, L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(add_mod::<M, L>(&self.0, &rhs.0), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Sub for MontyScalar<M, L> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(sub_mod::<M, L>(&self.0, &rhs.0), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Neg for MontyScalar<M, L> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Mul for MontyScalar<M, L> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let t = mont_mul::<M, L>(&self.0, &rhs.0);
+        Self(mont_mul::<M, L>(&t, &M::R2), PhantomData)
+    }
+}
+
+impl<M, const L: usize> Clone for MontyField<M, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const L: usize> Copy for MontyField<M, L> {}
+
+impl<M, const L: usize> Clone for MontyScalar<M, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const L: usize> Copy for MontyScalar<M, L> {}
+
+impl<M: Modulus<L>, const L: usize> Default for MontyField<M, L> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Default for MontyScalar<M, L> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<M, const L: usize> PartialEq for MontyField<M, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<M, const L: usize> Eq for MontyField<M, L> {}
+
+impl<M, const L: usize> PartialEq for MontyScalar<M, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<M, const L: usize> Eq for MontyScalar<M, L> {}
+
+impl<M: Modulus<L>, const L: usize> ConditionallySelectable for MontyField<M, L> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> ConditionallySelectable for MontyScalar<M, L> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+impl<M, const L: usize> ConstantTimeEq for MontyField<M, L> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<M, const L: usize> ConstantTimeEq for MontyScalar<M, L> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Debug for MontyField<M, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MontyField").field(&self.to_canonical()).finish()
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Debug for MontyScalar<M, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MontyScalar").field(&self.0).finish()
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Display for MontyField<M, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<M: Modulus<L>, const L: usize> Display for MontyScalar<M, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<M, const L: usize> Zeroize for MontyScalar<M, L> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Montgomery multiplication (CIOS): a·b·R⁻¹ mod m for a, b < m.
+fn mont_mul<M: Modulus<L>, const L: usize>(a: &[u64; L], b: &[u64; L]) -> [u64; L] {
+    let m = &M::MODULUS;
+    let mut t = [0u64; L];
+    // Bit 64L of t; t stays below 2m between rounds.
+    let mut t_hi = 0;
+    for &a_i in a {
+        let mut carry = 0;
+        for j in 0..L {
+            (t[j], carry) = mac(t[j], a_i, b[j], carry);
+        }
+        let (hi, hi2) = adc(t_hi, carry, 0);
+        // Add q·m to clear the low limb, then drop it.
+        let q = t[0].wrapping_mul(M::M0_INV);
+        let (_, mut carry) = mac(t[0], q, m[0], 0);
+        for j in 1..L {
+            (t[j - 1], carry) = mac(t[j], q, m[j], carry);
+        }
+        (t[L - 1], carry) = adc(hi, carry, 0);
+        t_hi = hi2 + carry;
+    }
+    reduce_once::<M, L>(t, t_hi)
+}
+
+fn pow<M: Modulus<L>, const L: usize>(base: &[u64; L], exp: &[u64; L]) -> [u64; L] {
+    let mut res = M::R;
+    for limb in exp.iter().rev() {
+        for bit in (0..64).rev() {
+            res = mont_mul::<M, L>(&res, &res);
+            if (limb >> bit) & 1 == 1 {
+                res = mont_mul::<M, L>(&res, base);
+            }
+        }
+    }
+    res
+}
+
+/// `value = carry·2^(64L) + limbs`, assumed below 2m; subtracts m once if needed.
+fn reduce_once<M: Modulus<L>, const L: usize>(limbs: [u64; L], carry: u64) -> [u64; L] {
+    let (diff, borrow) = sub_limbs(&limbs, &M::MODULUS);
+    select(&limbs, &diff, Choice::from((carry | (borrow ^ 1)) as u8))
+}
+
+fn add_mod<M: Modulus<L>, const L: usize>(a: &[u64; L], b: &[u64; L]) -> [u64; L] {
+    let mut sum = [0u64; L];
+    let mut carry = 0;
+    for (i, limb) in sum.iter_mut().enumerate() {
+        (*limb, carry) = adc(a[i], b[i], carry);
+    }
+    reduce_once::<M, L>(sum, carry)
+}
+
+fn sub_mod<M: Modulus<L>, const L: usize>(a: &[u64; L], b: &[u64; L]) -> [u64; L] {
+    let (diff, borrow) = sub_limbs(a, b);
+    let mask = u64::conditional_select(&0, &u64::MAX, Choice::from(borrow as u8));
+    let mut out = [0u64; L];
+    let mut carry = 0;
+    for (i, limb) in out.iter_mut().enumerate() {
+        (*limb, carry) = adc(diff[i], M::MODULUS[i] & mask, carry);
+    }
+    out
+}
+
+fn select<const L: usize>(a: &[u64; L], b: &[u64; L], choice: Choice) -> [u64; L] {
+    let mut out = [0u64; L];
+    for (i, limb) in out.iter_mut().enumerate() {
+        *limb = u64::conditional_select(&a[i], &b[i], choice);
+    }
+    out
+}
+
+fn is_below_modulus<M: Modulus<L>, const L: usize>(limbs: &[u64; L]) -> bool {
+    sub_limbs(limbs, &M::MODULUS).1 == 1
+}
+
+/// Parses exactly `BYTES` big-endian bytes, without any range check.
+fn limbs_from_be_bytes<M: Modulus<L>, const L: usize>(bytes: &[u8]) -> Option<[u64; L]> {
+    if bytes.len() != M::BYTES {
+        return None;
+    }
+    let mut limbs = [0u64; L];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 8] |= (*byte as u64) << (8 * (i % 8));
+    }
+    Some(limbs)
+}
+
+fn limbs_to_be_bytes<M: Modulus<L>, const L: usize>(limbs: &[u64; L]) -> Vec<u8> {
+    (0..M::BYTES).rev().map(|i| (limbs[i / 8] >> (8 * (i % 8))) as u8).collect()
+}
+
+const fn small<const L: usize>(value: u64) -> [u64; L] {
+    let mut out = [0u64; L];
+    out[0] = value;
+    out
+}
+
+const fn one<const L: usize>() -> [u64; L] {
+    small(1)
+}
+
+const fn sub_limbs<const L: usize>(a: &[u64; L], b: &[u64; L]) -> ([u64; L], u64) {
+    let mut out = [0u64; L];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < L {
+        (out[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    (out, borrow)
+}
+
+const fn add_one<const L: usize>(a: &[u64; L]) -> [u64; L] {
+    let mut out = [0u64; L];
+    let mut carry = 1;
+    let mut i = 0;
+    while i < L {
+        (out[i], carry) = adc(a[i], 0, carry);
+        i += 1;
+    }
+    out
+}
+
+/// a >> shift for 0 < shift < 64.
+const fn shr<const L: usize>(a: &[u64; L], shift: u32) -> [u64; L] {
+    let mut out = [0u64; L];
+    let mut i = 0;
+    while i < L {
+        out[i] = a[i] >> shift;
+        if i + 1 < L {
+            out[i] |= a[i + 1] << (64 - shift);
+        }
+        i += 1;
+    }
+    out
+}
+
+/// -m0⁻¹ mod 2^64 by Newton's iteration, which doubles the number of
+/// correct low bits at every step.
+const fn m0_inv(m0: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// x·2^count mod m for x < m, by repeated modular doubling. Compile time only.
+const fn double_times<const L: usize>(mut x: [u64; L], count: usize, m: &[u64; L]) -> [u64; L] {
+    let mut n = 0;
+    while n < count {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < L {
+            (x[i], carry) = adc(x[i], x[i], carry);
+            i += 1;
+        }
+        let (diff, borrow) = sub_limbs(&x, m);
+        if carry == 1 || borrow == 0 {
+            x = diff;
+        }
+        n += 1;
+    }
+    x
+}
@@ -0,0 +1,145 @@
+use hmac::{digest::{core_api::BlockSizeUser, Digest}, Mac, SimpleHmac};
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use crate::arithmetic::Modular;
+
+/// HMAC-DRBG nonce generator from RFC 6979, section 3.2.
+///
+/// Successive calls to `next_k` walk the same DRBG stream, so a signer that
+/// has to reject a nonce (e.g. because `r` came out as zero) just asks for
+/// the next one. The DRBG state `k`, `v` is wiped as it is replaced and
+/// on drop, and nonces are handed out as wiped byte buffers.
+pub struct Rfc6979<D: Digest + BlockSizeUser> {
+    /// n as `rlen` big-endian bytes, for the range check on candidates.
+    n: Vec<u8>,
+    qlen: u64,
+    rlen: usize,
+    k: Zeroizing<Vec<u8>>,
+    v: Zeroizing<Vec<u8>>,
+    first: bool,
+    _digest: std::marker::PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> Rfc6979<D> {
+    /// `n` is the group order, `x` the big-endian private key, already in
+    /// `[1, n - 1]`, and `h1` the message hash. The key is taken as bytes so
+    /// that it never passes through a `BigInt`, which cannot be wiped.
+    /// `extra_entropy` is the optional `k'` of section 3.6; it is mixed into
+    /// both seeding steps, right after `bits2octets(h1)`.
+    pub fn new(n: &BigInt, x: &[u8], h1: &[u8], extra_entropy: Option<&[u8]>) -> Self {
+        let qlen = n.bits();
+        let rlen = qlen.div_ceil(8) as usize;
+        let hlen = <D as Digest>::output_size();
+        let x_octets = secret_octets(x, rlen);
+        let h1_octets = bits2octets(h1, n);
+        let extra = extra_entropy.unwrap_or(&[]);
+
+        let mut v = Zeroizing::new(vec![0x01u8; hlen]);
+        let mut k = Zeroizing::new(vec![0x00u8; hlen]);
+        k = hmac::<D>(&k, &[&v, &[0x00], &x_octets, &h1_octets, extra]);
+        v = hmac::<D>(&k, &[&v]);
+        k = hmac::<D>(&k, &[&v, &[0x01], &x_octets, &h1_octets, extra]);
+        v = hmac::<D>(&k, &[&v]);
+
+        Self { n: int2octets(n, rlen), qlen, rlen, k, v, first: true, _digest: std::marker::PhantomData }
+    }
+
+    /// Returns the next candidate nonce in `[1, n - 1]`, as `rlen`
+    /// big-endian bytes. It never passes through a `BigInt`.
+    pub fn next_k(&mut self) -> Zeroizing<Vec<u8>> {
+        loop {
+            if !self.first {
+                self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+                self.v = hmac::<D>(&self.k, &[&self.v]);
+            }
+            self.first = false;
+
+            // Room for the last whole block, so `t` never reallocates.
+            let mut t = Zeroizing::new(Vec::with_capacity(self.rlen + self.v.len()));
+            while t.len() < self.rlen {
+                self.v = hmac::<D>(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let k = secret_bits2int(&t[..self.rlen], self.qlen);
+            if k.iter().any(|&b| b != 0) && k[..] < self.n[..] {
+                return k;
+            }
+        }
+    }
+}
+
+impl<D: Digest + BlockSizeUser> ZeroizeOnDrop for Rfc6979<D> {}
+
+/// Convenience wrapper returning the first nonce of the RFC 6979 stream.
+pub fn generate_k<D: Digest + BlockSizeUser>(
+    n: &BigInt,
+    x: &[u8],
+    h1: &[u8],
+    extra_entropy: Option<&[u8]>
+) -> Zeroizing<Vec<u8>> {
+    Rfc6979::<D>::new(n, x, h1, extra_entropy).next_k()
+}
+
+/// Interprets `bytes` as a big-endian integer and keeps its leftmost `qlen` bits.
+pub fn bits2int(bytes: &[u8], qlen: u64) -> BigInt {
+    let int = BigInt::from_bytes_be(Sign::Plus, bytes);
+    let blen = bytes.len() as u64 * 8;
+    if blen > qlen {
+        int >> (blen - qlen)
+    } else {
+        int
+    }
+}
+
+/// `bits2int` for a secret `rlen`-byte buffer: the leftmost `qlen` bits,
+/// shifted down in place and returned as `rlen` bytes again.
+fn secret_bits2int(bytes: &[u8], qlen: u64) -> Zeroizing<Vec<u8>> {
+    let mut out = Zeroizing::new(bytes.to_vec());
+    let shift = (bytes.len() as u64 * 8 - qlen) as u32;
+    if shift > 0 {
+        for i in (0..out.len()).rev() {
+            let carry = if i > 0 { out[i - 1] << (8 - shift) } else { 0 };
+            out[i] = (out[i] >> shift) | carry;
+        }
+    }
+    out
+}
+
+/// Big-endian encoding of `x` left-padded with zeroes to exactly `rlen` bytes.
+pub fn int2octets(x: &BigInt, rlen: usize) -> Vec<u8> {
+    let (_, bytes) = x.to_bytes_be();
+    if x.is_zero() {
+        return vec![0u8; rlen];
+    }
+    let mut out = vec![0u8; rlen.saturating_sub(bytes.len())];
+    out.extend_from_slice(&bytes[bytes.len().saturating_sub(rlen)..]);
+    out
+}
+
+/// `int2octets` for a secret already below n: `x` is left-padded with
+/// zeroes, or stripped of leading zeroes, to `rlen` bytes.
+fn secret_octets(x: &[u8], rlen: usize) -> Zeroizing<Vec<u8>> {
+    let skip = x.len().saturating_sub(rlen);
+    debug_assert!(x[..skip].iter().all(|&b| b == 0), "x must be below n");
+    let mut out = Zeroizing::new(vec![0u8; rlen.saturating_sub(x.len())]);
+    out.extend_from_slice(&x[skip..]);
+    out
+}
+
+pub fn bits2octets(bytes: &[u8], n: &BigInt) -> Vec<u8> {
+    let qlen = n.bits();
+    let rlen = qlen.div_ceil(8) as usize;
+    let z1 = bits2int(bytes, qlen);
+    int2octets(&z1.modulus(n), rlen)
+}
+
+fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Zeroizing<Vec<u8>> {
+    let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(key)
+        .expect("HMAC accepts keys of any length");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
@@ -0,0 +1,339 @@
+use std::{fmt::Display, ops::{Add, Mul, Neg, Sub}};
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Signed;
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{curve::ScalarField, field::{adc, mac, sbb, sub_limbs}};
+
+/// n, the order of the secp256k1 group, least significant limb first.
+pub(crate) const N: [u64; 4] = [0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+/// n - 2, the inversion exponent.
+const N_MINUS_2: [u64; 4] = [0xBFD25E8CD036413F, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+/// ⌊n / 2⌋; scalars above it are the "high" half.
+const N_HALF: [u64; 4] = [0xDFE92F46681B20A0, 0x5D576E7357A4501D, 0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF];
+/// ⌊2^512 / n⌋, the Barrett constant (257 bits).
+const MU: [u64; 5] = [0x402DA1732FC9BEC0, 0x4551231950B75FC4, 0x0000000000000001, 0x0000000000000000, 0x0000000000000001];
+
+/// Integer modulo the secp256k1 group order n, stored as four 64-bit limbs,
+/// least significant first. Values are always fully reduced, so anything
+/// outside `[0, n)` simply cannot be built.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scalar([u64; 4]);
+
+impl Scalar {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    pub const fn from_u64(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+
+    /// Little-endian limbs, which must already be below n.
+    #[cfg(feature = "glv")]
+    pub(crate) const fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self(limbs)
+    }
+
+    /// Parses a big-endian encoding, rejecting values ≥ n.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let limbs = Self::limbs_from_bytes(bytes);
+        let (_, borrow) = sub_limbs(&limbs, &N);
+        if borrow == 1 {
+            Some(Self(limbs))
+        } else {
+            None
+        }
+    }
+
+    /// Parses a big-endian encoding and reduces it modulo n. Any 256-bit
+    /// value is below 2n, so a single conditional subtraction is enough.
+    pub fn from_bytes_reduced(bytes: &[u8; 32]) -> Self {
+        Self::reduce_once(Self::limbs_from_bytes(bytes), 0)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            let start = 24 - 8 * i;
+            bytes[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Converts a `BigInt` in `[0, n)`; anything else gives `None`.
+    pub fn from_bigint(value: &BigInt) -> Option<Self> {
+        if value.is_negative() || value.bits() > 256 {
+            return None;
+        }
+        let (_, bytes) = value.to_bytes_be();
+        let mut padded = [0u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        Self::from_bytes(&padded)
+    }
+
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &self.to_bytes())
+    }
+
+    /// Uniformly random scalar in `[0, n)`, by rejection sampling.
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Some(scalar) = Self::from_bytes(&bytes) {
+                return scalar;
+            }
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO).into()
+    }
+
+    /// Whether the value is above ⌊n / 2⌋, i.e. whether -self is smaller.
+    pub fn is_high(&self) -> bool {
+        let (_, borrow) = sub_limbs(&N_HALF, &self.0);
+        borrow == 1
+    }
+
+    /// Bit `i` of the canonical value, counting from the least significant.
+    pub fn bit(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    pub(crate) fn limbs(&self) -> &[u64; 4] {
+        &self.0
+    }
+
+    /// ⌊self·g / 2^384⌉, the plain integer product shifted right with
+    /// rounding. Used by the GLV decomposition, where the result is below 2^128.
+    #[cfg(feature = "glv")]
+    pub(crate) fn mul_shift_384(&self, g: &[u64; 4]) -> Self {
+        let t = mul_wide(&self.0, g);
+        let round = t[5] >> 63;
+        let (lo, carry) = adc(t[6], round, 0);
+        let (hi, _) = adc(t[7], 0, carry);
+        Self([lo, hi, 0, 0])
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Raises `self` to a public exponent. The running time depends on the
+    /// exponent only, never on `self`.
+    pub fn pow(&self, exp: &[u64; 4]) -> Self {
+        let mut res = Self::ONE;
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                res = res.square();
+                if (limb >> bit) & 1 == 1 {
+                    res = res * *self;
+                }
+            }
+        }
+        res
+    }
+
+    /// Multiplicative inverse via Fermat's little theorem, a^(n-2). The
+    /// exponent is fixed, so this runs in constant time for secret values.
+    pub fn invert(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&N_MINUS_2))
+    }
+
+    fn limbs_from_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 24 - 8 * i;
+            *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().unwrap());
+        }
+        limbs
+    }
+
+    /// `value = carry·2^256 + limbs`, assumed below 2n; subtracts n once if needed.
+    fn reduce_once(limbs: [u64; 4], carry: u64) -> Self {
+        let (diff, borrow) = sub_limbs(&limbs, &N);
+        let use_diff = Choice::from((carry | (borrow ^ 1)) as u8);
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&limbs[i], &diff[i], use_diff);
+        }
+        Self(out)
+    }
+
+    /// Barrett reduction of a 512-bit product (HAC, algorithm 14.42, with
+    /// b = 2^64 and k = 4). The quotient estimate is off by at most two, and
+    /// both corrections are done with conditional selects.
+    fn reduce_wide(t: [u64; 8]) -> Self {
+        // q3 = ⌊⌊t / b^3⌋ · μ / b^5⌋
+        let mut q2 = [0u64; 10];
+        for i in 0..5 {
+            let mut carry = 0;
+            for j in 0..5 {
+                (q2[i + j], carry) = mac(q2[i + j], t[i + 3], MU[j], carry);
+            }
+            q2[i + 5] = carry;
+        }
+        let q3 = &q2[5..];
+
+        // r = (t - q3·n) mod b^5, which lies in [0, 3n).
+        let mut qn = [0u64; 5];
+        for i in 0..5 {
+            let mut carry = 0;
+            for j in 0..(5 - i).min(4) {
+                (qn[i + j], carry) = mac(qn[i + j], q3[i], N[j], carry);
+            }
+            if i == 0 {
+                qn[4] = carry;
+            }
+        }
+        let mut r = [0u64; 5];
+        let mut borrow = 0;
+        for i in 0..5 {
+            (r[i], borrow) = sbb(t[i], qn[i], borrow);
+        }
+
+        for _ in 0..2 {
+            let mut diff = [0u64; 5];
+            let mut borrow = 0;
+            for i in 0..5 {
+                (diff[i], borrow) = sbb(r[i], if i < 4 { N[i] } else { 0 }, borrow);
+            }
+            let use_diff = Choice::from((borrow ^ 1) as u8);
+            for (limb, d) in r.iter_mut().zip(diff) {
+                limb.conditional_assign(&d, use_diff);
+            }
+        }
+        Self([r[0], r[1], r[2], r[3]])
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut sum = [0u64; 4];
+        let mut carry = 0;
+        for (i, limb) in sum.iter_mut().enumerate() {
+            (*limb, carry) = adc(self.0[i], rhs.0[i], carry);
+        }
+        Self::reduce_once(sum, carry)
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    /// n - self, selected back to zero when `self` is zero.
+    fn neg(self) -> Self::Output {
+        let (diff, _) = sub_limbs(&N, &self.0);
+        let is_zero = self.ct_eq(&Self::ZERO);
+        Self(diff.map(|limb| u64::conditional_select(&limb, &0, is_zero)))
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::reduce_wide(mul_wide(&self.0, &rhs.0))
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Self(out)
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[0].ct_eq(&other.0[0])
+            & self.0[1].ct_eq(&other.0[1])
+            & self.0[2].ct_eq(&other.0[2])
+            & self.0[3].ct_eq(&other.0[3])
+    }
+}
+
+impl ScalarField for Scalar {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const BYTES: usize = 32;
+    const NUM_BITS: usize = 256;
+    const MODULUS: &'static [u64] = &N;
+
+    fn from_u64(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+
+    fn is_zero(&self) -> bool {
+        Scalar::is_zero(self)
+    }
+
+    fn is_high(&self) -> bool {
+        Scalar::is_high(self)
+    }
+
+    fn invert(&self) -> Option<Self> {
+        Scalar::invert(self)
+    }
+
+    fn limbs(&self) -> &[u64] {
+        &self.0
+    }
+
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes.try_into().ok()?)
+    }
+
+    fn from_be_bytes_reduced(bytes: &[u8]) -> Self {
+        Self::from_bytes_reduced(bytes.try_into().expect("32 bytes"))
+    }
+
+    fn to_be_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+/// Full 512-bit product of two 256-bit values.
+fn mul_wide(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let mut t = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0;
+        for j in 0..4 {
+            (t[i + j], carry) = mac(t[i + j], a[i], b[j], carry);
+        }
+        t[i + 4] = carry;
+    }
+    t
+}
@@ -0,0 +1,231 @@
+use std::fmt::Debug;
+
+use num_traits::Zero;
+use rand::{thread_rng, CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConditionallySelectable};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{arithmetic::Secp256k1Point, curve::{CurveParams, Field, ScalarField}, ecdsa::random_weight, error::{BatchError, Error}, field::FieldElement, scalar::Scalar, secp256k1::{PrivateKey, PublicKey, Secp256k1Params}};
+
+/// `SHA256(SHA256(tag) || SHA256(tag) || parts...)`, the domain separated
+/// hash BIP340 uses for nonces and challenges.
+pub fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// A BIP340 public key: 32 bytes of x, standing for the point with that x
+/// and an even y.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XOnlyPublicKey(Secp256k1Point);
+
+impl XOnlyPublicKey {
+    /// `lift_x`: fails unless x < p and x³ + 7 is a square.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != 32 {
+            return Err(Error::InvalidEncoding);
+        }
+        let x = FieldElement::from_be_bytes(bytes).ok_or(Error::PointNotOnCurve)?;
+        Ok(Self(Secp256k1Point::from_x(x, false)?))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        x_bytes(&self.0)
+    }
+
+    /// The even-y point the key stands for.
+    pub fn point(&self) -> &Secp256k1Point {
+        &self.0
+    }
+}
+
+impl From<&PublicKey> for XOnlyPublicKey {
+    /// Drops y. A key with odd y maps to its negation, which shares its x.
+    fn from(public_key: &PublicKey) -> Self {
+        let point = &public_key.0;
+        match point.y {
+            Some(y) if y.is_odd() => Self(-point.clone()),
+            _ => Self(point.clone()),
+        }
+    }
+}
+
+/// A secret key together with its x-only public key. The secret is kept
+/// negated when d·G has an odd y, so that it matches the even-y point the
+/// public key stands for. Wiped on drop, like `PrivateKey`.
+pub struct KeyPair {
+    secret: Scalar,
+    public_key: XOnlyPublicKey,
+}
+
+impl KeyPair {
+    pub fn new(private_key: &PrivateKey) -> Self {
+        let d = private_key.0;
+        let point = Secp256k1Params::mul_generator(&d).to_affine();
+        let y_is_odd = point.y.expect("d is in [1, n - 1]").is_odd();
+        let secret = Scalar::conditional_select(&d, &-d, Choice::from(y_is_odd as u8));
+        let public_key = XOnlyPublicKey(if y_is_odd { -point } else { point });
+        Self { secret, public_key }
+    }
+
+    /// Parses a big-endian secret in `[1, n - 1]`; see `PrivateKey::from_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(&PrivateKey::from_bytes(bytes)?))
+    }
+
+    pub fn public_key(&self) -> &XOnlyPublicKey {
+        &self.public_key
+    }
+}
+
+impl Debug for KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("secret", &"<redacted>")
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for KeyPair {}
+
+/// A BIP340 signature: the x coordinate of R and the scalar s, encoded as
+/// `bytes(R.x) || bytes(s)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub r: FieldElement,
+    pub s: Scalar,
+}
+
+impl Signature {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+
+    /// Parses 64 bytes. r must be below p and s below n.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != 64 {
+            return Err(Error::InvalidEncoding);
+        }
+        let r = FieldElement::from_be_bytes(&bytes[..32]).ok_or(Error::InvalidEncoding)?;
+        let s = Scalar::from_be_bytes(&bytes[32..]).ok_or(Error::InvalidScalar)?;
+        Ok(Self { r, s })
+    }
+}
+
+/// Signs `message` as BIP340 describes. `aux_rand` should be 32 fresh random
+/// bytes. It is masked into the secret before hashing, so the nonce stays
+/// unpredictable even if the randomness is weak. All zeros still gives a
+/// valid, deterministic signature.
+///
+/// The signature is verified before it is returned, as BIP340 recommends
+/// against fault attacks. The key copy, the masked key, the nonce hash and
+/// k are wiped on every return path.
+pub fn sign(message: &[u8], keypair: &KeyPair, aux_rand: &[u8; 32]) -> Result<Signature, Error> {
+    let d = Zeroizing::new(keypair.secret);
+    let mut t = Zeroizing::new(d.to_be_bytes());
+    for (byte, mask) in t.iter_mut().zip(tagged_hash("BIP0340/aux", &[aux_rand])) {
+        *byte ^= mask;
+    }
+    let p = keypair.public_key.to_bytes();
+    let rand = Zeroizing::new(tagged_hash("BIP0340/nonce", &[&t[..], &p, message]));
+    let k = Zeroizing::new(Scalar::from_be_bytes_reduced(&rand[..]));
+    if k.is_zero() {
+        return Err(Error::InvalidScalar);
+    }
+    let big_r = Secp256k1Params::mul_generator(&k).to_affine();
+    let (r, r_y) = match (big_r.x, big_r.y) {
+        (Some(r), Some(r_y)) => (r, r_y),
+        _ => return Err(Error::PointAtInfinity),
+    };
+    let k = Zeroizing::new(Scalar::conditional_select(&k, &-*k, Choice::from(r_y.is_odd() as u8)));
+    let e = challenge(&x_bytes(&big_r), &p, message);
+    let signature = Signature { r, s: *k + e * *d };
+    verify(message, &keypair.public_key, &signature)?;
+    Ok(signature)
+}
+
+/// Checks a BIP340 signature: R = s·G - e·P must be a finite point with an
+/// even y and x equal to r. Returns `Error::InvalidSignature` otherwise.
+pub fn verify(message: &[u8], public_key: &XOnlyPublicKey, signature: &Signature) -> Result<(), Error> {
+    let e = challenge(&signature.r.to_be_bytes(), &public_key.to_bytes(), message);
+    let big_r = Secp256k1Point::mul_add(&signature.s, &public_key.0, &-e);
+    match (big_r.x, big_r.y) {
+        (Some(x), Some(y)) if !y.is_odd() && x == signature.r => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
+
+/// Verifies many `(public key, message, signature)` triples with BIP340's
+/// BatchVerify, using weights from the thread-local CSPRNG; see
+/// `verify_batch_with_rng`.
+pub fn verify_batch(items: &[(&XOnlyPublicKey, &[u8], &Signature)]) -> Result<(), BatchError> {
+    verify_batch_with_rng(items, &mut thread_rng())
+}
+
+/// BatchVerify: with a₁ = 1 and random weights aᵢ in `[1, n - 1]`, checks
+///
+///   (Σ aᵢ·sᵢ)·G = Σ aᵢ·Rᵢ + Σ (aᵢ·eᵢ)·Pᵢ
+///
+/// in a single multi-scalar multiplication, Rᵢ being the even-y point with
+/// x = rᵢ. A forged entry passes only if the weights happen to cancel its
+/// error, which has negligible probability. The weights must not be
+/// predictable to whoever picked the signatures.
+///
+/// The combined check cannot tell which entry is bad, so when it fails, or
+/// some rᵢ is not an x coordinate, the entries are verified one by one and
+/// the first failure is reported.
+pub fn verify_batch_with_rng(items: &[(&XOnlyPublicKey, &[u8], &Signature)], rng: &mut (impl CryptoRng + RngCore)) -> Result<(), BatchError> {
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_sum = Scalar::ZERO;
+    for (index, (public_key, message, signature)) in items.iter().enumerate() {
+        let Ok(big_r) = Secp256k1Point::from_x(signature.r, false) else {
+            return verify_each(items);
+        };
+        let e = challenge(&signature.r.to_be_bytes(), &public_key.to_bytes(), message);
+        let a = if index == 0 { Scalar::ONE } else { random_weight(rng) };
+        s_sum = s_sum + a * signature.s;
+        terms.push((a, big_r));
+        terms.push((a * e, public_key.0.clone()));
+    }
+    terms.push((-s_sum, Secp256k1Params::get().g.clone()));
+    if Secp256k1Point::lincomb(&terms).is_zero() {
+        return Ok(());
+    }
+    verify_each(items)
+}
+
+/// Finds the first entry that fails on its own. Should all of them pass,
+/// their combination holds too, so the batch is valid after all.
+fn verify_each(items: &[(&XOnlyPublicKey, &[u8], &Signature)]) -> Result<(), BatchError> {
+    for (index, (public_key, message, signature)) in items.iter().enumerate() {
+        verify(message, public_key, signature).map_err(|error| BatchError { index, error })?;
+    }
+    Ok(())
+}
+
+/// e = int(hash_BIP0340/challenge(bytes(R) || bytes(P) || m)) mod n.
+fn challenge(r: &[u8], p: &[u8], message: &[u8]) -> Scalar {
+    Scalar::from_be_bytes_reduced(&tagged_hash("BIP0340/challenge", &[r, p, message]))
+}
+
+fn x_bytes(point: &Secp256k1Point) -> [u8; 32] {
+    let x = point.x.expect("not the point at infinity");
+    x.to_be_bytes().try_into().expect("32-byte field element")
+}
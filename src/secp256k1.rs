@@ -1,42 +1,423 @@
+use std::sync::OnceLock;
+
 use num_bigint::BigInt;
-use num_traits::Zero;
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::{arithmetic::{AffinePoint, JacobianPoint, Secp256k1Point}, curve::{CurveParams, Field}, ecdsa, field::FieldElement, generator::mul_generator, montgomery::{Modulus, MontyField, MontyScalar}, scalar::Scalar};
+#[cfg(not(feature = "glv"))]
+use crate::{generator::GeneratorTable, wnaf::{self, OddMultiples}};
+#[cfg(feature = "glv")]
+use crate::glv;
+
+// The ECDSA types specialised to secp256k1.
+pub type PrivateKey = ecdsa::PrivateKey<Secp256k1Params>;
+pub type PublicKey = ecdsa::PublicKey<Secp256k1Params>;
+pub type Signature = ecdsa::Signature<Secp256k1Params>;
+pub type RecoverableSignature = ecdsa::RecoverableSignature<Secp256k1Params>;
 
-use crate::arithmetic::Secp256k1Point;
+#[derive(Debug)]
 pub struct Secp256k1Params {
-    pub a: BigInt,
-    pub b: BigInt,
+    pub a: FieldElement,
+    pub b: FieldElement,
     pub p: BigInt,
     pub g: Secp256k1Point,
-    pub n: BigInt
+    pub n: BigInt,
+    pub h: u64,
 }
 
 impl Secp256k1Params {
-    pub fn get() -> Self {
+    /// The curve constants, parsed once on first use and shared afterwards.
+    pub fn get() -> &'static Self {
+        static PARAMS: OnceLock<Secp256k1Params> = OnceLock::new();
+        PARAMS.get_or_init(Self::init)
+    }
+
+    fn init() -> Self {
         Self { 
-            a: Zero::zero(), 
-            b: BigInt::parse_bytes(
-                b"0000000000000000000000000000000000000000000000000000000000000007",
-                16
-            ).unwrap(), 
+            a: FieldElement::ZERO, 
+            b: FieldElement::from_u64(7), 
             p: BigInt::parse_bytes(
                 b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 
                 16
             ).unwrap(),
             g: Secp256k1Point::free_dot(
-                    BigInt::parse_bytes(
+                    FieldElement::from_bigint(&BigInt::parse_bytes(
                         b"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798", 
                         16
-                    ).unwrap(),
-                    BigInt::parse_bytes(
+                    ).unwrap()).unwrap(),
+                    FieldElement::from_bigint(&BigInt::parse_bytes(
                         b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8", 
                         16
-                    ).unwrap(),
+                    ).unwrap()).unwrap(),
                 ),
             n: BigInt::parse_bytes(
                 b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
                 16
             ).unwrap(),
-            
+            h: 1,
         }
     }
-}
\ No newline at end of file
+}
+
+impl CurveParams for Secp256k1Params {
+    type Field = FieldElement;
+    type Scalar = Scalar;
+    type MessageDigest = Sha256;
+
+    fn get() -> &'static Self {
+        Secp256k1Params::get()
+    }
+
+    fn p(&self) -> &BigInt {
+        &self.p
+    }
+
+    fn a(&self) -> FieldElement {
+        self.a
+    }
+
+    fn b(&self) -> FieldElement {
+        self.b
+    }
+
+    fn g(&self) -> &Secp256k1Point {
+        &self.g
+    }
+
+    fn n(&self) -> &BigInt {
+        &self.n
+    }
+
+    fn h(&self) -> u64 {
+        self.h
+    }
+
+    /// Uses the precomputed table in `generator`.
+    fn mul_generator(k: &Scalar) -> JacobianPoint {
+        mul_generator(k)
+    }
+
+    #[cfg(feature = "glv")]
+    fn mul(p: &Secp256k1Point, k: &Scalar) -> JacobianPoint {
+        glv::mul(p, k)
+    }
+
+    /// G takes its cached width-8 table; P gets a fresh width-5 one.
+    #[cfg(not(feature = "glv"))]
+    fn mul_add(u: &Scalar, p: &Secp256k1Point, v: &Scalar) -> JacobianPoint {
+        let g_table = OddMultiples::Affine(GeneratorTable::get().odd_multiples());
+        let p_table = OddMultiples::Jacobian(wnaf::odd_multiples(p, wnaf::POINT_WINDOW));
+        wnaf::strauss(&[
+            (wnaf::wnaf(u, wnaf::GENERATOR_WINDOW), g_table),
+            (wnaf::wnaf(v, wnaf::POINT_WINDOW), p_table),
+        ])
+    }
+
+    /// Both scalars are split by the GLV endomorphism, giving four
+    /// half-length terms.
+    #[cfg(feature = "glv")]
+    fn mul_add(u: &Scalar, p: &Secp256k1Point, v: &Scalar) -> JacobianPoint {
+        glv::mul_add(u, p, v)
+    }
+}
+
+/// p = 2^256 - 2^224 + 2^192 + 2^96 - 1, the P-256 field prime.
+#[derive(Debug)]
+pub struct P256Prime;
+
+impl Modulus<4> for P256Prime {
+    const MODULUS: [u64; 4] = [0xFFFFFFFFFFFFFFFF, 0x00000000FFFFFFFF, 0x0000000000000000, 0xFFFFFFFF00000001];
+}
+
+/// n, the order of the P-256 group.
+#[derive(Debug)]
+pub struct P256Order;
+
+impl Modulus<4> for P256Order {
+    const MODULUS: [u64; 4] = [0xF3B9CAC2FC632551, 0xBCE6FAADA7179E84, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000];
+}
+
+pub type P256FieldElement = MontyField<P256Prime, 4>;
+pub type P256Scalar = MontyScalar<P256Order, 4>;
+/// An affine P-256 point.
+pub type P256Point = AffinePoint<P256Params>;
+
+/// NIST P-256 (secp256r1), from FIPS 186-4, appendix D.1.2.3. Unlike
+/// secp256k1 it has a = -3, and it relies on the generic scalar
+/// multiplication hooks of `CurveParams`.
+#[derive(Debug)]
+pub struct P256Params {
+    pub a: P256FieldElement,
+    pub b: P256FieldElement,
+    pub p: BigInt,
+    pub g: P256Point,
+    pub n: BigInt,
+    pub h: u64,
+}
+
+impl P256Params {
+    /// The curve constants, parsed once on first use and shared afterwards.
+    pub fn get() -> &'static Self {
+        static PARAMS: OnceLock<P256Params> = OnceLock::new();
+        PARAMS.get_or_init(Self::init)
+    }
+
+    fn init() -> Self {
+        let element = |hex: &[u8]| {
+            P256FieldElement::from_bigint(&BigInt::parse_bytes(hex, 16).unwrap()).unwrap()
+        };
+        Self {
+            a: -P256FieldElement::from_u64(3),
+            b: element(b"5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"),
+            p: BigInt::parse_bytes(
+                b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                16
+            ).unwrap(),
+            g: P256Point::free_dot(
+                element(b"6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+                element(b"4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+            ),
+            n: BigInt::parse_bytes(
+                b"ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                16
+            ).unwrap(),
+            h: 1,
+        }
+    }
+}
+
+impl CurveParams for P256Params {
+    type Field = P256FieldElement;
+    type Scalar = P256Scalar;
+    type MessageDigest = Sha256;
+
+    fn get() -> &'static Self {
+        P256Params::get()
+    }
+
+    fn p(&self) -> &BigInt {
+        &self.p
+    }
+
+    fn a(&self) -> P256FieldElement {
+        self.a
+    }
+
+    fn b(&self) -> P256FieldElement {
+        self.b
+    }
+
+    fn g(&self) -> &P256Point {
+        &self.g
+    }
+
+    fn n(&self) -> &BigInt {
+        &self.n
+    }
+
+    fn h(&self) -> u64 {
+        self.h
+    }
+}
+
+/// p = 2^384 - 2^128 - 2^96 + 2^32 - 1, the P-384 field prime.
+#[derive(Debug)]
+pub struct P384Prime;
+
+impl Modulus<6> for P384Prime {
+    const MODULUS: [u64; 6] = [0x00000000FFFFFFFF, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+}
+
+/// n, the order of the P-384 group.
+#[derive(Debug)]
+pub struct P384Order;
+
+impl Modulus<6> for P384Order {
+    const MODULUS: [u64; 6] = [0xECEC196ACCC52973, 0x581A0DB248B0A77A, 0xC7634D81F4372DDF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+}
+
+pub type P384FieldElement = MontyField<P384Prime, 6>;
+pub type P384Scalar = MontyScalar<P384Order, 6>;
+/// An affine P-384 point.
+pub type P384Point = AffinePoint<P384Params>;
+
+/// NIST P-384 (secp384r1), from FIPS 186-4, appendix D.1.2.4. Messages
+/// are hashed with SHA-384 by default.
+#[derive(Debug)]
+pub struct P384Params {
+    pub a: P384FieldElement,
+    pub b: P384FieldElement,
+    pub p: BigInt,
+    pub g: P384Point,
+    pub n: BigInt,
+    pub h: u64,
+}
+
+impl P384Params {
+    /// The curve constants, parsed once on first use and shared afterwards.
+    pub fn get() -> &'static Self {
+        static PARAMS: OnceLock<P384Params> = OnceLock::new();
+        PARAMS.get_or_init(Self::init)
+    }
+
+    fn init() -> Self {
+        let element = |hex: &[u8]| {
+            P384FieldElement::from_bigint(&BigInt::parse_bytes(hex, 16).unwrap()).unwrap()
+        };
+        Self {
+            a: -P384FieldElement::from_u64(3),
+            b: element(b"b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef"),
+            p: BigInt::parse_bytes(
+                b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+                16
+            ).unwrap(),
+            g: P384Point::free_dot(
+                element(b"aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7"),
+                element(b"3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f"),
+            ),
+            n: BigInt::parse_bytes(
+                b"ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                16
+            ).unwrap(),
+            h: 1,
+        }
+    }
+}
+
+impl CurveParams for P384Params {
+    type Field = P384FieldElement;
+    type Scalar = P384Scalar;
+    type MessageDigest = Sha384;
+
+    fn get() -> &'static Self {
+        P384Params::get()
+    }
+
+    fn p(&self) -> &BigInt {
+        &self.p
+    }
+
+    fn a(&self) -> P384FieldElement {
+        self.a
+    }
+
+    fn b(&self) -> P384FieldElement {
+        self.b
+    }
+
+    fn g(&self) -> &P384Point {
+        &self.g
+    }
+
+    fn n(&self) -> &BigInt {
+        &self.n
+    }
+
+    fn h(&self) -> u64 {
+        self.h
+    }
+}
+
+/// p = 2^521 - 1, the P-521 field prime.
+#[derive(Debug)]
+pub struct P521Prime;
+
+impl Modulus<9> for P521Prime {
+    const MODULUS: [u64; 9] = [
+        0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x00000000000001FF,
+    ];
+}
+
+/// n, the order of the P-521 group.
+#[derive(Debug)]
+pub struct P521Order;
+
+impl Modulus<9> for P521Order {
+    const MODULUS: [u64; 9] = [
+        0xBB6FB71E91386409, 0x3BB5C9B8899C47AE, 0x7FCC0148F709A5D0, 0x51868783BF2F966B, 0xFFFFFFFFFFFFFFFA,
+        0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x00000000000001FF,
+    ];
+}
+
+pub type P521FieldElement = MontyField<P521Prime, 9>;
+pub type P521Scalar = MontyScalar<P521Order, 9>;
+/// An affine P-521 point.
+pub type P521Point = AffinePoint<P521Params>;
+
+/// NIST P-521 (secp521r1), from FIPS 186-4, appendix D.1.2.5. Messages
+/// are hashed with SHA-512 by default.
+#[derive(Debug)]
+pub struct P521Params {
+    pub a: P521FieldElement,
+    pub b: P521FieldElement,
+    pub p: BigInt,
+    pub g: P521Point,
+    pub n: BigInt,
+    pub h: u64,
+}
+
+impl P521Params {
+    /// The curve constants, parsed once on first use and shared afterwards.
+    pub fn get() -> &'static Self {
+        static PARAMS: OnceLock<P521Params> = OnceLock::new();
+        PARAMS.get_or_init(Self::init)
+    }
+
+    fn init() -> Self {
+        let element = |hex: &[u8]| {
+            P521FieldElement::from_bigint(&BigInt::parse_bytes(hex, 16).unwrap()).unwrap()
+        };
+        Self {
+            a: -P521FieldElement::from_u64(3),
+            b: element(b"0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00"),
+            p: BigInt::parse_bytes(
+                b"01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                16
+            ).unwrap(),
+            g: P521Point::free_dot(
+                element(b"00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"),
+                element(b"011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650"),
+            ),
+            n: BigInt::parse_bytes(
+                b"01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+                16
+            ).unwrap(),
+            h: 1,
+        }
+    }
+}
+
+impl CurveParams for P521Params {
+    type Field = P521FieldElement;
+    type Scalar = P521Scalar;
+    type MessageDigest = Sha512;
+
+    fn get() -> &'static Self {
+        P521Params::get()
+    }
+
+    fn p(&self) -> &BigInt {
+        &self.p
+    }
+
+    fn a(&self) -> P521FieldElement {
+        self.a
+    }
+
+    fn b(&self) -> P521FieldElement {
+        self.b
+    }
+
+    fn g(&self) -> &P521Point {
+        &self.g
+    }
+
+    fn n(&self) -> &BigInt {
+        &self.n
+    }
+
+    fn h(&self) -> u64 {
+        self.h
+    }
+}
@@ -2,8 +2,9 @@
 mod tests {    
 
     use num_bigint::BigInt;
+    use num_traits::Zero;
     use state_manager::{Getter, State, StateBuffer, StateManager, StateSetter, error};
-    use crate::arithmetic::{Modular, Secp256k1Point};
+    use crate::{arithmetic::{JacobianPoint, Modular, Secp256k1Point}, error::Error, field::FieldElement, scalar::Scalar, secp256k1::Secp256k1Params};
 
     pub struct TestStateBuffer;
     pub type BigIntTestData1 = Vec<(BigInt, BigInt, BigInt)>;
@@ -40,6 +41,8 @@ mod tests {
                 (Some(BigInt::from(3)), Some(BigInt::from(11)), Some(BigInt::from(4))),
                 (Some(BigInt::from(10)), Some(BigInt::from(17)), Some(BigInt::from(12))),
                 (Some(BigInt::from(2)), Some(BigInt::from(5)), Some(BigInt::from(3))),
+                (Some(BigInt::from(-3)), Some(BigInt::from(11)), Some(BigInt::from(7))),
+                (Some(BigInt::from(-10)), Some(BigInt::from(17)), Some(BigInt::from(5))),
             ]
         ));
         for val in test_state.get().unwrap() {
@@ -98,6 +101,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_x() {
+        let g = Secp256k1Params::get().g.clone();
+        let g_x = g.x.unwrap();
+        let even = Secp256k1Point::from_x(g_x, false).unwrap();
+        let odd = Secp256k1Point::from_x(g_x, true).unwrap();
+        assert_eq!(even, g);
+        assert_eq!(odd, -g);
+        assert!(odd.is_on_curve());
+
+        let (test_state, _) = TestStateBuffer::new_state(Some(
+            vec![1u64, 2, 3, 8]
+        ));
+        for x in test_state.get().unwrap() {
+            let point = Secp256k1Point::from_x(FieldElement::from_u64(x), true).unwrap();
+            assert!(point.is_on_curve());
+            assert!(point.y.unwrap().is_odd());
+        }
+    }
+
+    #[test]
+    fn test_from_x_not_on_curve() {
+        let (test_state, _) = TestStateBuffer::new_state(Some(vec![0u64, 5, 7]));
+        for x in test_state.get().unwrap() {
+            let x = FieldElement::from_u64(x);
+            assert_eq!(Secp256k1Point::from_x(x, false), Err(Error::PointNotOnCurve));
+        }
+    }
+
+    fn hex(s: &str) -> FieldElement {
+        FieldElement::from_bigint(&BigInt::parse_bytes(s.as_bytes(), 16).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_times_known_multiples() {
+        let g = Secp256k1Params::get().g.clone();
+        let (test_state, _) = TestStateBuffer::new_state(Some(
+            vec![
+                (
+                    BigInt::from(1),
+                    "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                    "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+                ),
+                (
+                    BigInt::from(2),
+                    "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+                    "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+                ),
+                (
+                    BigInt::from(3),
+                    "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                    "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+                ),
+                (
+                    BigInt::from(4),
+                    "E493DBF1C10D80F3581E4904930B1404CC6C13900EE0758474FA94ABE8C4CD13",
+                    "51ED993EA0D455B75642E2098EA51448D967AE33BFBDFE40CFE97BDC47739922",
+                ),
+                (
+                    BigInt::from(5),
+                    "2F8BDE4D1A07209355B4A7250A5C5128E88B84BDDC619AB7CBA8D569B240EFE4",
+                    "D8AC222636E5E3D6D4DBA9DDA6C9C426F788271BAB0D6840DCA87D3AA6AC62D6",
+                ),
+                (
+                    BigInt::from(112233445566778899u64),
+                    "A90CC3D3F3E146DAADFC74CA1372207CB4B725AE708CEF713A98EDD73D99EF29",
+                    "5A79D6B289610C68BC3B47F3D72F9788A26A06868B4D8E433E1E2AD76FB7DC76",
+                ),
+                (
+                    BigInt::parse_bytes(b"112233445566778899112233445566778899", 10).unwrap(),
+                    "E5A2636BCFD412EBF36EC45B19BFB68A1BC5F8632E678132B885F7DF99C5E9B3",
+                    "736C1CE161AE27B405CAFD2A7520370153C2C861AC51D6C1D5985D9606B45F39",
+                ),
+            ]
+        ));
+        for (k, x, y) in test_state.get().unwrap() {
+            let k = Scalar::from_bigint(&k).unwrap();
+            assert_eq!(g.times(&k), Secp256k1Point::free_dot(hex(x), hex(y)));
+        }
+    }
+
+    #[test]
+    fn test_times_edge_scalars() {
+        let g = Secp256k1Params::get().g.clone();
+        assert_eq!(g.times(&Scalar::ZERO), Secp256k1Point::zero());
+        assert_eq!(g.times(&Scalar::ONE), g);
+        assert_eq!(g.times(&-Scalar::ONE), -g.clone());
+        assert_eq!(g.times(&-Scalar::from_u64(3)), -g.times(&Scalar::from_u64(3)));
+        assert_eq!(Secp256k1Point::zero().times(&Scalar::from_u64(7)), Secp256k1Point::zero());
+    }
+
+    #[test]
+    fn test_times_ct_matches_times() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from_u64(2),
+            -Scalar::from_u64(5),
+            -Scalar::ONE,
+            // Small enough that k + n still has bit 256 clear.
+            Scalar::from_bigint(&(BigInt::from(1) << 100)).unwrap(),
+        ];
+        for _ in 0..8 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+        for k in scalars {
+            assert_eq!(g.times_ct(&k), g.times(&k), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_jacobian_matches_affine() {
+        let g = Secp256k1Params::get().g.clone();
+        let g3 = g.times(&Scalar::from_u64(3));
+        let jg = JacobianPoint::from(&g);
+        let jg3 = JacobianPoint::from(&g3);
+        assert_eq!(jg.to_affine(), g);
+        assert_eq!(jg.double().to_affine(), g.times_two());
+        assert_eq!((jg + jg3).to_affine(), &g + &g3);
+        // Adding two points that are both off Z = 1 exercises the general formula.
+        let (a, b) = (jg.double(), jg3.double());
+        assert_eq!((a + b).to_affine(), g.times(&Scalar::from_u64(8)));
+        assert_eq!(a + b, JacobianPoint::from(&g.times(&Scalar::from_u64(8))));
+    }
+
+    #[test]
+    fn test_jacobian_special_cases() {
+        let jg = JacobianPoint::from(&Secp256k1Params::get().g);
+        let zero = JacobianPoint::zero();
+        assert!((jg + -jg).is_zero());
+        assert_eq!(jg + jg, jg.double());
+        assert_eq!(jg + zero, jg);
+        assert_eq!(zero + jg, jg);
+        assert!(zero.double().is_zero());
+        assert_eq!(zero.to_affine(), Secp256k1Point::zero());
+        assert_eq!(JacobianPoint::from(&Secp256k1Point::zero()), zero);
+        assert_ne!(jg, zero);
+    }
+
     // #[test]
     // fn test_mod() {
     //     let x = BigInt::from(-4);
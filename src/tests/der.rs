@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::{error::{DerError, Error}, scalar::Scalar, secp256k1::{PrivateKey, Secp256k1Params, Signature}};
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn signature(r: u64, s: u64) -> Signature {
+        Signature { r: Scalar::from_u64(r), s: Scalar::from_u64(s) }
+    }
+
+    #[test]
+    fn test_der_encoding() {
+        assert_eq!(signature(1, 1).to_der(), from_hex("3006020101020101"));
+        assert_eq!(signature(0x80, 0x7f).to_der(), from_hex("30070202008002017f"));
+
+        let sign = Signature::sign_message("temp msg", &PrivateKey::generate()).unwrap();
+        let der = sign.to_der();
+        assert!(der.len() <= 72);
+        let parsed = Signature::from_der(&der).unwrap();
+        assert_eq!((parsed.r, parsed.s), (sign.r, sign.s));
+    }
+
+    #[test]
+    fn test_der_rejects_malformed() {
+        let vectors = [
+            ("3106020101020101", Error::Der(DerError::InvalidSequence)),
+            ("30060201010201", Error::Der(DerError::InvalidSequence)),
+            ("3007020101020101", Error::Der(DerError::InvalidLength)),
+            ("300602010102010100", Error::Der(DerError::TrailingData)),
+            ("3006030101020101", Error::Der(DerError::InvalidInteger)),
+            ("3006020002020101", Error::Der(DerError::InvalidInteger)),
+            ("3006020181020101", Error::Der(DerError::NegativeInteger)),
+            ("300702020001020101", Error::Der(DerError::NonMinimalInteger)),
+            ("3008020101020101", Error::Der(DerError::InvalidLength)),
+            ("308106020101020101", Error::Der(DerError::InvalidLength)),
+            ("3006020100020101", Error::InvalidScalar),
+            (
+                "3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141020101",
+                Error::InvalidScalar,
+            ),
+        ];
+        for (der, err) in vectors {
+            assert_eq!(Signature::from_der(&from_hex(der)).unwrap_err(), err, "{}", der);
+        }
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        let sign = signature(1, 2);
+        let compact = sign.to_compact();
+        assert_eq!(compact[31], 1);
+        assert_eq!(compact[63], 2);
+        let parsed = Signature::from_compact(&compact).unwrap();
+        assert_eq!((parsed.r, parsed.s), (sign.r, sign.s));
+
+        assert_eq!(Signature::from_compact(&compact[..63]).unwrap_err(), Error::InvalidEncoding);
+        assert_eq!(Signature::from_compact(&[0u8; 64]).unwrap_err(), Error::InvalidScalar);
+
+        let mut n_as_s = [0u8; 64];
+        n_as_s[31] = 1;
+        let (_, n) = Secp256k1Params::get().n.to_bytes_be();
+        n_as_s[32..].copy_from_slice(&n);
+        assert_eq!(Signature::from_compact(&n_as_s).unwrap_err(), Error::InvalidScalar);
+    }
+}
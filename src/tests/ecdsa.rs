@@ -1,7 +1,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::ecdsa::{PrivateKey, PublicKey, Signature};
+    use num_bigint::BigInt;
+    use rand::{rngs::StdRng, SeedableRng};
+    use sha2::{Digest, Sha256, Sha512};
+
+    use crate::{arithmetic::Modular, ecdsa::{self, hash_to_scalar, recover_public_key}, error::{BatchError, Error}, field::FieldElement, scalar::Scalar, secp256k1::{PrivateKey, PublicKey, RecoverableSignature, Secp256k1Params, Signature}};
 
     fn pk_gen() -> PrivateKey {
         PrivateKey::generate()
@@ -16,15 +20,322 @@ mod tests {
         }
     }
 
-    //паникует, найти ошибку
+    #[test]
+    fn test_private_key_bytes() {
+        let priv_key = PrivateKey::random(&mut rand::thread_rng());
+        assert_eq!(PrivateKey::from_bytes(&priv_key.to_bytes()), Ok(priv_key));
+
+        let n = Secp256k1Params::get().n.clone();
+        let encode = |v: &BigInt| -> [u8; 32] {
+            let (_, bytes) = v.to_bytes_be();
+            let mut padded = [0u8; 32];
+            padded[32 - bytes.len()..].copy_from_slice(&bytes);
+            padded
+        };
+        assert_eq!(PrivateKey::from_bytes(&[0u8; 32]), Err(Error::InvalidScalar));
+        assert_eq!(PrivateKey::from_bytes(&encode(&n)), Err(Error::InvalidScalar));
+        assert_eq!(PrivateKey::from_bytes(&[0xff; 32]), Err(Error::InvalidScalar));
+        assert_eq!(*PrivateKey::from_bytes(&encode(&(&n - 1))).unwrap().to_bytes(), encode(&(&n - 1)));
+        assert_eq!(*PrivateKey::from_bytes(&encode(&BigInt::from(1))).unwrap().to_bytes(), encode(&BigInt::from(1)));
+    }
+
     #[test]
     fn test_sign_validation() {
         let priv_key = PrivateKey::generate();
-        let pub_key = PublicKey::new(&priv_key);
+        let pub_key = PublicKey::new(&priv_key).unwrap();
+        let msg = "temp msg";
+        let sign = Signature::sign_message(msg, &priv_key).unwrap();
+        let validation = Signature::validate(msg, &pub_key, &sign);
+        assert!(validation.is_ok());
+    }
+
+    #[test]
+    fn test_sign_prehashed_matches_sign_message() {
+        let priv_key = pk_gen();
+        let msg = "temp msg";
+        let hash: [u8; 32] = Sha256::digest(msg).into();
+        let sign = Signature::sign_message(msg, &priv_key).unwrap();
+        let prehashed = Signature::sign_prehashed(&hash, &priv_key).unwrap();
+        assert_eq!((sign.r, sign.s), (prehashed.r, prehashed.s));
+    }
+
+    #[test]
+    fn test_sign_digest_depends_on_hash() {
+        let priv_key = pk_gen();
+        let msg = "temp msg";
+        let sha256 = Signature::sign_digest(Sha256::new_with_prefix(msg), &priv_key).unwrap();
+        let sha512 = Signature::sign_digest(Sha512::new_with_prefix(msg), &priv_key).unwrap();
+        assert_ne!(sha256.r, sha512.r);
+    }
+
+    #[test]
+    fn test_hash_to_scalar_truncation() {
+        let n = Secp256k1Params::get().n.clone();
+        let hash = Sha512::digest(b"temp msg");
+        let e = hash_to_scalar::<Secp256k1Params>(&hash);
+        assert_eq!(e.to_bigint(), BigInt::from_bytes_be(num_bigint::Sign::Plus, &hash[..32]).modulus(&n));
+
+        let short = [0xffu8; 20];
+        assert_eq!(hash_to_scalar::<Secp256k1Params>(&short).to_bigint(), BigInt::from_bytes_be(num_bigint::Sign::Plus, &short));
+
+        // Digests at or above n wrap around.
+        assert_eq!(hash_to_scalar::<Secp256k1Params>(&[0xffu8; 32]).to_bigint(), (BigInt::from(1) << 256u32) - 1u8 - &n);
+    }
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_sec1_encoding() {
+        let g = ecdsa::PublicKey(Secp256k1Params::get().g.clone());
+        let compressed = from_hex("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        let uncompressed = from_hex(
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+            483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+        assert_eq!(g.to_sec1_bytes(true), compressed);
+        assert_eq!(g.to_sec1_bytes(false), uncompressed);
+        assert_eq!(PublicKey::from_sec1_bytes(&compressed).unwrap(), g);
+        assert_eq!(PublicKey::from_sec1_bytes(&uncompressed).unwrap(), g);
+
+        let neg_g = ecdsa::PublicKey(-Secp256k1Params::get().g.clone());
+        let neg_compressed = neg_g.to_sec1_bytes(true);
+        assert_eq!(neg_compressed[0], 0x03);
+        assert_eq!(PublicKey::from_sec1_bytes(&neg_compressed).unwrap(), neg_g);
+    }
+
+    #[test]
+    fn test_sec1_rejects_invalid_points() {
+        assert_eq!(PublicKey::from_sec1_bytes(&[0x00]), Err(Error::PointAtInfinity));
+        assert_eq!(PublicKey::from_sec1_bytes(&[]), Err(Error::InvalidEncoding));
+
+        let mut compressed = vec![0x02];
+        compressed.extend([0u8; 31]);
+        compressed.push(5);
+        assert_eq!(PublicKey::from_sec1_bytes(&compressed), Err(Error::PointNotOnCurve));
+        compressed[0] = 0x05;
+        assert_eq!(PublicKey::from_sec1_bytes(&compressed), Err(Error::InvalidEncoding));
+        assert_eq!(PublicKey::from_sec1_bytes(&compressed[..32]), Err(Error::InvalidEncoding));
+
+        let mut uncompressed = ecdsa::PublicKey(Secp256k1Params::get().g.clone()).to_sec1_bytes(false);
+        uncompressed[64] ^= 1;
+        assert_eq!(PublicKey::from_sec1_bytes(&uncompressed), Err(Error::PointNotOnCurve));
+
+        let p = from_hex("02fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        assert_eq!(PublicKey::from_sec1_bytes(&p), Err(Error::PointNotOnCurve));
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_scalars() {
+        let pub_key = ecdsa::PublicKey(Secp256k1Params::get().g.clone());
+        let vectors = [
+            Signature { r: Scalar::ONE, s: Scalar::ZERO },
+            Signature { r: Scalar::ZERO, s: Scalar::ONE },
+        ];
+        for sign in vectors {
+            assert_eq!(Signature::validate("temp msg", &pub_key, &sign), Err(Error::InvalidScalar));
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_public_key() {
+        let sign = Signature { r: Scalar::ONE, s: Scalar::ONE };
+        let infinity = ecdsa::PublicKey(crate::arithmetic::Secp256k1Point::default());
+        assert_eq!(Signature::validate("temp msg", &infinity, &sign), Err(Error::PointAtInfinity));
+        let off_curve = ecdsa::PublicKey(crate::arithmetic::Secp256k1Point::free_dot(FieldElement::ONE, FieldElement::ONE));
+        assert_eq!(Signature::validate("temp msg", &off_curve, &sign), Err(Error::PointNotOnCurve));
+    }
+
+    #[test]
+    fn test_zero_private_key() {
+        assert_eq!(PrivateKey::from_bytes(&Scalar::ZERO.to_bytes()), Err(Error::InvalidScalar));
+    }
+
+    #[test]
+    fn test_private_key_debug_redacted() {
+        let priv_key = PrivateKey::from_bytes(&Scalar::from_u64(0xdeadbeef).to_bytes()).unwrap();
+        let debug = format!("{:?}", priv_key);
+        assert_eq!(debug, "PrivateKey(<redacted>)");
+        assert!(!debug.contains(&0xdeadbeefu64.to_string()));
+    }
+
+    #[test]
+    fn test_verify_each_batch_inverted() {
+        let messages = ["msg 0", "msg 1", "msg 2", "msg 3", "msg 4", "msg 5"];
+        let priv_keys: Vec<PrivateKey> = messages.iter().map(|_| pk_gen()).collect();
+        let pub_keys: Vec<PublicKey> = priv_keys.iter().map(|pk| PublicKey::new(pk).unwrap()).collect();
+        let signs: Vec<Signature> = messages.iter().zip(&priv_keys)
+            .map(|(msg, pk)| Signature::sign_message(msg, pk).unwrap())
+            .collect();
+        let items: Vec<(&str, &PublicKey, &Signature)> = messages.iter().zip(&pub_keys).zip(&signs)
+            .map(|((msg, pk), sign)| (*msg, pk, sign))
+            .collect();
+        assert_eq!(Signature::verify_each_batch_inverted(&items), Ok(()));
+        assert_eq!(Signature::verify_each_batch_inverted(&[]), Ok(()));
+
+        let mut tampered = items.clone();
+        tampered[3].0 = "other msg";
+        assert_eq!(Signature::verify_each_batch_inverted(&tampered), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+
+        let mut wrong_key = items.clone();
+        wrong_key[4].1 = &pub_keys[0];
+        assert_eq!(Signature::verify_each_batch_inverted(&wrong_key), Err(BatchError { index: 4, error: Error::InvalidSignature }));
+
+        // Entries are judged in index order, whether they are malformed or
+        // fail their equation.
+        let zero_s = Signature { r: Scalar::ONE, s: Scalar::ZERO };
+        let mut invalid = items.clone();
+        invalid[5].2 = &zero_s;
+        assert_eq!(Signature::verify_each_batch_inverted(&invalid), Err(BatchError { index: 5, error: Error::InvalidScalar }));
+        invalid[3].0 = "other msg";
+        assert_eq!(Signature::verify_each_batch_inverted(&invalid), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+        invalid[1].2 = &zero_s;
+        assert_eq!(Signature::verify_each_batch_inverted(&invalid), Err(BatchError { index: 1, error: Error::InvalidScalar }));
+    }
+
+    #[test]
+    fn test_recoverable_verify_batch() {
+        let messages: Vec<String> = (0..6).map(|i| format!("msg {}", i)).collect();
+        let priv_keys: Vec<PrivateKey> = messages.iter().map(|_| pk_gen()).collect();
+        let pub_keys: Vec<PublicKey> = priv_keys.iter().map(|pk| PublicKey::new(pk).unwrap()).collect();
+        let signs: Vec<RecoverableSignature> = messages.iter().zip(&priv_keys)
+            .map(|(msg, pk)| RecoverableSignature::sign_message(msg, pk).unwrap())
+            .collect();
+        let items: Vec<(&str, &PublicKey, &RecoverableSignature)> = messages.iter().zip(&pub_keys).zip(&signs)
+            .map(|((msg, pk), sign)| (msg.as_str(), pk, sign))
+            .collect();
+        assert_eq!(RecoverableSignature::verify_batch(&items), Ok(()));
+        assert_eq!(RecoverableSignature::verify_batch(&[]), Ok(()));
+        assert_eq!(RecoverableSignature::verify_batch_with_rng(&items, &mut StdRng::seed_from_u64(1)), Ok(()));
+
+        let mut tampered = items.clone();
+        tampered[3].0 = "other msg";
+        assert_eq!(RecoverableSignature::verify_batch(&tampered), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+
+        let mut wrong_key = items.clone();
+        wrong_key[4].1 = &pub_keys[0];
+        assert_eq!(RecoverableSignature::verify_batch(&wrong_key), Err(BatchError { index: 4, error: Error::InvalidSignature }));
+
+        // A valid (r, s) with the wrong parity in its recovery id names the
+        // wrong R, and recovery would give a different key.
+        let flipped = RecoverableSignature { r: signs[2].r, s: signs[2].s, recid: signs[2].recid ^ 1 };
+        let mut wrong_recid = items.clone();
+        wrong_recid[2].2 = &flipped;
+        assert_eq!(RecoverableSignature::verify_batch(&wrong_recid), Err(BatchError { index: 2, error: Error::InvalidSignature }));
+
+        let bad_recid = RecoverableSignature { r: signs[5].r, s: signs[5].s, recid: 4 };
+        let mut invalid = tampered.clone();
+        invalid[5].2 = &bad_recid;
+        assert_eq!(RecoverableSignature::verify_batch(&invalid), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+        invalid[3].0 = "msg 3";
+        assert_eq!(RecoverableSignature::verify_batch(&invalid), Err(BatchError { index: 5, error: Error::InvalidRecoveryId }));
+    }
+
+    // Over 64 entries the combination has more than `PIPPENGER_THRESHOLD` terms.
+    #[test]
+    fn test_recoverable_verify_batch_large() {
+        let priv_key = pk_gen();
+        let pub_key = PublicKey::new(&priv_key).unwrap();
+        let messages: Vec<String> = (0..70).map(|i| format!("msg {}", i)).collect();
+        let signs: Vec<RecoverableSignature> = messages.iter()
+            .map(|msg| RecoverableSignature::sign_message(msg, &priv_key).unwrap())
+            .collect();
+        let mut items: Vec<(&str, &PublicKey, &RecoverableSignature)> = messages.iter().zip(&signs)
+            .map(|(msg, sign)| (msg.as_str(), &pub_key, sign))
+            .collect();
+        assert_eq!(RecoverableSignature::verify_batch(&items), Ok(()));
+        items[50].0 = "other msg";
+        assert_eq!(RecoverableSignature::verify_batch(&items), Err(BatchError { index: 50, error: Error::InvalidSignature }));
+    }
+
+    // Two entries under shifted keys whose errors cancel in an unweighted
+    // sum: r_a·(P + G) + r_b·(P - (r_a / r_b)·G) = r_a·P + r_b·P.
+    #[test]
+    fn test_recoverable_verify_batch_weights() {
+        let g = Secp256k1Params::get().g.clone();
+        let priv_key = pk_gen();
+        let pub_key = PublicKey::new(&priv_key).unwrap();
+        let sign_a = RecoverableSignature::sign_message("a", &priv_key).unwrap();
+        let sign_b = RecoverableSignature::sign_message("b", &priv_key).unwrap();
+        let shift = sign_a.r * sign_b.r.invert().unwrap();
+        let key_a = ecdsa::PublicKey(&pub_key.0 + &g);
+        let key_b = ecdsa::PublicKey(&pub_key.0 + &-g.times(&shift));
+        let items = [("a", &key_a, &sign_a), ("b", &key_b, &sign_b)];
+        for seed in 0..4 {
+            let result = RecoverableSignature::verify_batch_with_rng(&items, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(result, Err(BatchError { index: 0, error: Error::InvalidSignature }));
+        }
+    }
+
+    #[test]
+    fn test_recover_public_key() {
         let msg = "temp msg";
-        let sign = Signature::sign_message(msg, &priv_key);
-        println!("sign: {:?}", sign);
-        let validation = Signature::validate(msg, &pub_key, &sign.0);
-        assert!(validation);
+        let hash: [u8; 32] = Sha256::digest(msg).into();
+        for _ in 0..8 {
+            let priv_key = pk_gen();
+            let pub_key = PublicKey::new(&priv_key).unwrap();
+            let recoverable = RecoverableSignature::sign_message(msg, &priv_key).unwrap();
+            let sign = Signature::sign_message(msg, &priv_key).unwrap();
+            assert_eq!((recoverable.r, recoverable.s), (sign.r, sign.s));
+            assert_eq!(recover_public_key(&hash, &recoverable).unwrap(), pub_key);
+
+            let flipped = RecoverableSignature { recid: recoverable.recid ^ 1, ..recoverable };
+            assert_ne!(recover_public_key(&hash, &flipped).unwrap(), pub_key);
+        }
+    }
+
+    #[test]
+    fn test_recover_public_key_vector() {
+        // d = 1, message "Satoshi Nakamoto": the low-S s pairs with an odd R.y.
+        let hash: [u8; 32] = Sha256::digest("Satoshi Nakamoto").into();
+        let recoverable = RecoverableSignature::sign_prehashed(&hash, &PrivateKey::from_bytes(&Scalar::ONE.to_bytes()).unwrap()).unwrap();
+        let bytes = recoverable.to_bytes();
+        assert_eq!(bytes[..32], from_hex("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"));
+        assert_eq!(bytes[32..64], from_hex("2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"));
+        assert_eq!(bytes[64], 1);
+        let pub_key = recover_public_key(&hash, &recoverable).unwrap();
+        assert_eq!(pub_key.0, Secp256k1Params::get().g);
+    }
+
+    #[test]
+    fn test_recoverable_encoding() {
+        let priv_key = pk_gen();
+        let recoverable = RecoverableSignature::sign_message("temp msg", &priv_key).unwrap();
+        let mut bytes = recoverable.to_bytes();
+        let decoded = RecoverableSignature::from_bytes(&bytes).unwrap();
+        assert_eq!((decoded.r, decoded.s, decoded.recid), (recoverable.r, recoverable.s, recoverable.recid));
+
+        bytes[64] += 27;
+        assert_eq!(RecoverableSignature::from_bytes(&bytes).unwrap().recid, recoverable.recid);
+        bytes[64] = 4;
+        assert_eq!(RecoverableSignature::from_bytes(&bytes).unwrap_err(), Error::InvalidRecoveryId);
+        assert_eq!(RecoverableSignature::from_bytes(&bytes[..64]).unwrap_err(), Error::InvalidEncoding);
+
+        let hash: [u8; 32] = Sha256::digest("temp msg").into();
+        let invalid = RecoverableSignature { recid: 4, ..recoverable };
+        assert_eq!(recover_public_key(&hash, &invalid).unwrap_err(), Error::InvalidRecoveryId);
+    }
+
+    #[test]
+    fn test_low_s() {
+        for _ in 0..16 {
+            let priv_key = pk_gen();
+            let pub_key = PublicKey::new(&priv_key).unwrap();
+            let sign = Signature::sign_message("temp msg", &priv_key).unwrap();
+            assert!(sign.is_low_s());
+            assert_eq!(Signature::validate_strict("temp msg", &pub_key, &sign), Ok(()));
+            let normalized = sign.normalize_s();
+            assert_eq!((normalized.r, normalized.s), (sign.r, sign.s));
+
+            // The malleated twin still verifies, but not strictly.
+            let high = Signature { r: sign.r, s: -sign.s };
+            assert!(!high.is_low_s());
+            assert_eq!(Signature::validate("temp msg", &pub_key, &high), Ok(()));
+            assert_eq!(Signature::validate_strict("temp msg", &pub_key, &high), Err(Error::HighS));
+            let hash: [u8; 32] = Sha256::digest("temp msg").into();
+            assert_eq!(Signature::verify_prehashed_strict(&hash, &pub_key, &high), Err(Error::HighS));
+            assert_eq!(high.normalize_s().s, sign.s);
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt};
+    use num_traits::One;
+
+    use crate::{arithmetic::Modular, field::FieldElement, secp256k1::Secp256k1Params};
+
+    fn random_pair(p: &BigInt) -> (BigInt, FieldElement) {
+        let value = rand::thread_rng().gen_bigint_range(&BigInt::from(0), p);
+        let element = FieldElement::from_bigint(&value).unwrap();
+        (value, element)
+    }
+
+    #[test]
+    fn test_field_ops_match_bigint() {
+        let p = Secp256k1Params::get().p.clone();
+        for _ in 0..200 {
+            let (a, fa) = random_pair(&p);
+            let (b, fb) = random_pair(&p);
+            assert_eq!((fa + fb).to_bigint(), (&a + &b).modulus(&p));
+            assert_eq!((fa - fb).to_bigint(), (&a - &b).modulus(&p));
+            assert_eq!((fa * fb).to_bigint(), (&a * &b).modulus(&p));
+            assert_eq!((-fa).to_bigint(), (-&a).modulus(&p));
+            if let Some(inv) = fa.invert() {
+                assert_eq!(inv.to_bigint(), a.invmod(&p).unwrap());
+                assert_eq!(inv * fa, FieldElement::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_edge_cases() {
+        let p = Secp256k1Params::get().p.clone();
+        let max = FieldElement::from_bigint(&(&p - BigInt::one())).unwrap();
+        assert_eq!(max + FieldElement::ONE, FieldElement::ZERO);
+        assert_eq!(FieldElement::ZERO - FieldElement::ONE, max);
+        assert_eq!(max * max, FieldElement::ONE);
+        assert_eq!(max.double(), max - FieldElement::ONE);
+        assert_eq!(FieldElement::ZERO.invert(), None);
+        assert_eq!(-FieldElement::ZERO, FieldElement::ZERO);
+    }
+
+    #[test]
+    fn test_field_encoding() {
+        let p = Secp256k1Params::get().p.clone();
+        assert_eq!(FieldElement::from_bigint(&p), None);
+        assert_eq!(FieldElement::from_bigint(&BigInt::from(-1)), None);
+        assert_eq!(FieldElement::from_bytes(&[0xff; 32]), None);
+        let (value, element) = random_pair(&p);
+        assert_eq!(FieldElement::from_bytes(&element.to_bytes()), Some(element));
+        assert_eq!(element.to_bigint(), value);
+        assert_eq!(element.to_string(), value.to_string());
+    }
+
+    #[test]
+    fn test_field_sqrt() {
+        let p = Secp256k1Params::get().p.clone();
+        for _ in 0..50 {
+            let (_, a) = random_pair(&p);
+            let root = a.square().sqrt().unwrap();
+            assert!(root == a || root == -a);
+        }
+        // -1 is a non-residue since p ≡ 3 (mod 4).
+        assert_eq!((-FieldElement::ONE).sqrt(), None);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::Zero;
+
+    use crate::{arithmetic::{JacobianPoint, Secp256k1Point}, generator::mul_generator, scalar::Scalar, secp256k1::Secp256k1Params};
+
+    #[test]
+    fn test_mul_generator_matches_times() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![
+            Scalar::ONE,
+            Scalar::from_u64(15),
+            Scalar::from_u64(16),
+            Scalar::from_u64(17),
+            // The running sum meets the selected entry: 16·G in window 1.
+            Scalar::from_u64(0x0f),
+            Scalar::from_u64(0xef0f),
+            -Scalar::ONE,
+            Scalar::from_bigint(&(BigInt::from(1) << 255u32)).unwrap(),
+        ];
+        for _ in 0..16 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+        for k in scalars {
+            assert_eq!(mul_generator(&k).to_affine(), g.times(&k), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_mul_generator_zero() {
+        assert!(mul_generator(&Scalar::ZERO).is_zero());
+    }
+
+    #[test]
+    fn test_batch_to_affine() {
+        let g = JacobianPoint::from(&Secp256k1Params::get().g);
+        let points = vec![g, g.double(), JacobianPoint::zero(), g.double() + g];
+        let affine = JacobianPoint::batch_to_affine(&points);
+        for (point, normalized) in points.iter().zip(affine.iter()) {
+            assert_eq!(&point.to_affine(), normalized);
+        }
+        assert_eq!(affine[2], Secp256k1Point::zero());
+        assert!(JacobianPoint::batch_to_affine(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_add_affine() {
+        let g = Secp256k1Params::get().g.clone();
+        let (x, y) = (g.x.unwrap(), g.y.unwrap());
+        let jg = JacobianPoint::from(&g);
+        assert_eq!(JacobianPoint::zero().add_affine(&x, &y), jg);
+        assert_eq!(jg.add_affine(&x, &y), jg.double());
+        assert!(jg.add_affine(&x, &-y).is_zero());
+        assert_eq!(jg.double().add_affine(&x, &y).to_affine(), g.times(&Scalar::from_u64(3)));
+    }
+
+    #[test]
+    fn test_add_affine_ct() {
+        let g = Secp256k1Params::get().g.clone();
+        let (x, y) = (g.x.unwrap(), g.y.unwrap());
+        let jg = JacobianPoint::from(&g);
+        assert_eq!(JacobianPoint::zero().add_affine_ct(&x, &y), jg);
+        assert_eq!(jg.add_affine_ct(&x, &y), jg.double());
+        assert!(jg.add_affine_ct(&x, &-y).is_zero());
+        let jg3 = jg.double().add_affine_ct(&x, &y);
+        assert_eq!(jg3.to_affine(), g.times(&Scalar::from_u64(3)));
+        // Same point as (x, y) but with Z ≠ 1.
+        assert_eq!(jg3.add_affine_ct(&x, &-y).add_affine_ct(&x, &y), jg3);
+        assert_eq!(jg.double().add_affine_ct(&x, &y).add_affine_ct(&x, &y), jg.double().double());
+    }
+}
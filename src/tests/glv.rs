@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use num_traits::Zero;
+
+    use crate::{arithmetic::Secp256k1Point, glv::{self, BETA, LAMBDA}, field::FieldElement, scalar::Scalar, secp256k1::Secp256k1Params};
+
+    fn sample_scalars() -> Vec<Scalar> {
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE, LAMBDA, -LAMBDA, Scalar::from_u64(u64::MAX)];
+        for _ in 0..16 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+        scalars
+    }
+
+    #[test]
+    fn test_cube_roots() {
+        assert_eq!(LAMBDA * LAMBDA * LAMBDA, Scalar::ONE);
+        assert_eq!(BETA * BETA * BETA, FieldElement::ONE);
+    }
+
+    #[test]
+    fn test_decompose() {
+        for k in sample_scalars() {
+            let (k1, k2) = glv::decompose(&k);
+            assert_eq!(k1 + k2 * LAMBDA, k, "k = {}", k);
+            for half in [k1, k2] {
+                let small = if half.is_high() { -half } else { half };
+                assert!(small.to_bytes()[..16].iter().all(|&b| b == 0), "k = {}, half = {}", k, half);
+            }
+        }
+    }
+
+    #[test]
+    fn test_endomorphism() {
+        let g = Secp256k1Params::get().g.clone();
+        assert_eq!(glv::endomorphism(&g), g.times_ct(&LAMBDA));
+        assert_eq!(glv::endomorphism(&Secp256k1Point::zero()), Secp256k1Point::zero());
+    }
+
+    #[test]
+    fn test_mul_matches_ladder() {
+        let g = Secp256k1Params::get().g.clone();
+        let p = g.times_ct(&Scalar::random(&mut rand::thread_rng()));
+        for k in sample_scalars() {
+            assert_eq!(glv::mul(&g, &k).to_affine(), g.times_ct(&k), "k = {}", k);
+            assert_eq!(p.times(&k), p.times_ct(&k), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_mul_add_matches_ladder() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        let p = g.times_ct(&Scalar::random(&mut rng));
+        let scalars = sample_scalars();
+        for (u, v) in scalars.iter().zip(scalars.iter().rev()) {
+            let expected = g.times_ct(u) + p.times_ct(v);
+            assert_eq!(glv::mul_add(u, &p, v).to_affine(), expected, "u = {}, v = {}", u, v);
+        }
+    }
+}
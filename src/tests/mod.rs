@@ -1,2 +1,15 @@
 pub mod arithmetic;
-pub mod ecdsa;
\ No newline at end of file
+pub mod der;
+pub mod ecdsa;
+pub mod field;
+pub mod generator;
+#[cfg(feature = "glv")]
+pub mod glv;
+pub mod p256;
+pub mod p384;
+pub mod p521;
+pub mod rfc6979;
+pub mod scalar;
+pub mod schnorr;
+pub mod timing;
+pub mod wnaf;
\ No newline at end of file
@@ -0,0 +1,295 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt};
+    use num_traits::Zero;
+    use sha2::{Digest, Sha256};
+
+    use crate::{
+        arithmetic::{HomogeneousPoint, Modular, ProjectivePoint},
+        curve::{Field, ScalarField},
+        ecdsa::{PrivateKey, PublicKey, Signature},
+        error::Error,
+        secp256k1::{P256FieldElement, P256Params, P256Point, P256Scalar},
+    };
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn scalar(s: &str) -> P256Scalar {
+        P256Scalar::from_be_bytes(&from_hex(s)).unwrap()
+    }
+
+    fn random_point() -> P256Point {
+        let k = P256Scalar::random(&mut rand::thread_rng());
+        P256Params::get().g.times(&k)
+    }
+
+    #[test]
+    fn test_p256_field_ops_match_bigint() {
+        let p = P256Params::get().p.clone();
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let a = rng.gen_bigint_range(&BigInt::from(1), &p);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &p);
+            let fa = P256FieldElement::from_bigint(&a).unwrap();
+            let fb = P256FieldElement::from_bigint(&b).unwrap();
+            assert_eq!((fa + fb).to_bigint(), (&a + &b).modulus(&p));
+            assert_eq!((fa - fb).to_bigint(), (&a - &b).modulus(&p));
+            assert_eq!((fa * fb).to_bigint(), (&a * &b).modulus(&p));
+            assert_eq!((-fb).to_bigint(), (-&b).modulus(&p));
+            assert_eq!(fa.invert().unwrap().to_bigint(), a.invmod(&p).unwrap());
+            assert_eq!(fa.is_odd(), a.bit(0));
+            let root = fa.square().sqrt().unwrap();
+            assert!(root == fa || root == -fa);
+        }
+        assert!(P256FieldElement::from_bigint(&p).is_none());
+        assert!(P256FieldElement::ZERO.invert().is_none());
+    }
+
+    #[test]
+    fn test_p256_scalar_ops_match_bigint() {
+        let n = P256Params::get().n.clone();
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let a = rng.gen_bigint_range(&BigInt::from(1), &n);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &n);
+            let sa = P256Scalar::from_bigint(&a).unwrap();
+            let sb = P256Scalar::from_bigint(&b).unwrap();
+            assert_eq!((sa + sb).to_bigint(), (&a + &b).modulus(&n));
+            assert_eq!((sa - sb).to_bigint(), (&a - &b).modulus(&n));
+            assert_eq!((sa * sb).to_bigint(), (&a * &b).modulus(&n));
+            assert_eq!(sa.invert().unwrap().to_bigint(), a.invmod(&n).unwrap());
+            assert_eq!(sa.is_high(), a > (&n >> 1));
+        }
+        assert!(P256Scalar::from_bigint(&n).is_none());
+        let x = from_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        assert_eq!(P256Scalar::from_be_bytes_reduced(&x).to_bigint(), BigInt::from_bytes_be(num_bigint::Sign::Plus, &x) - &n);
+    }
+
+    #[test]
+    fn test_p256_generator_order() {
+        let params = P256Params::get();
+        assert!(params.g.is_on_curve());
+        let minus_one = -P256Scalar::ONE;
+        assert_eq!(params.g.times(&minus_one), -params.g.clone());
+        assert!((params.g.times(&minus_one) + params.g.clone()).is_zero());
+    }
+
+    #[test]
+    fn test_complete_formulas() {
+        for _ in 0..20 {
+            let (p, q) = (random_point(), random_point());
+            let (hp, hq) = (HomogeneousPoint::from(&p), HomogeneousPoint::from(&q));
+            assert_eq!((hp + hq).to_affine(), &p + &q);
+            assert_eq!((hp + hp).to_affine(), p.times_two());
+            assert_eq!(hp.double().to_affine(), p.times_two());
+            assert!((hp + -hp).is_identity());
+            assert_eq!((hp + HomogeneousPoint::identity()).to_affine(), p);
+            assert_eq!((HomogeneousPoint::identity() + hq).to_affine(), q);
+            assert_eq!((hp + hq).to_jacobian(), ProjectivePoint::from(&p) + ProjectivePoint::from(&q));
+        }
+        let identity = HomogeneousPoint::<P256Params>::identity();
+        assert!((identity + identity).is_identity());
+        assert!(identity.double().is_identity());
+    }
+
+    #[test]
+    fn test_p256_times_ct() {
+        let mut rng = rand::thread_rng();
+        let p = random_point();
+        for k in [P256Scalar::ZERO, P256Scalar::ONE, -P256Scalar::ONE, P256Scalar::random(&mut rng)] {
+            let expected = p.times(&k);
+            assert_eq!(HomogeneousPoint::from(&p).times_ct(&k).to_affine(), expected);
+            assert_eq!(p.times_ct(&k), expected);
+        }
+    }
+
+    // FIPS 186-4 CAVP, SigGen.txt, [P-256,SHA-256]: the first two entries.
+    const SIG_GEN: [(&str, &str, &str, &str, &str, &str); 2] = [
+        (
+            "5905238877c77421f73e43ee3da6f2d9e2ccad5fc942dcec0cbd25482935faaf416983fe165b1a045ee2bcd2e6dca3bdf46c4310a7461f9a37960ca672d3feb5473e253605fb1ddfd28065b53cb5858a8ad28175bf9bd386a5e471ea7a65c17cc934a9d791e91491eb3754d03799790fe2d308d16146d5c9b0d0debd97d79ce8",
+            "519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464",
+            "1ccbe91c075fc7f4f033bfa248db8fccd3565de94bbfb12f3c59ff46c271bf83",
+            "ce4014c68811f9a21a1fdb2c0e6113e06db7ca93b7404e78dc7ccd5ca89a4ca9",
+            "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac",
+            "8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903",
+        ),
+        (
+            "c35e2f092553c55772926bdbe87c9796827d17024dbb9233a545366e2e5987dd344deb72df987144b8c6c43bc41b654b94cc856e16b96d7a821c8ec039b503e3d86728c494a967d83011a0e090b5d54cd47f4e366c0912bc808fbb2ea96efac88fb3ebec9342738e225f7c7c2b011ce375b56621a20642b4d36e060db4524af1",
+            "0f56db78ca460b055c500064824bed999a25aaf48ebb519ac201537b85479813",
+            "e266ddfdc12668db30d4ca3e8f7749432c416044f2d2b8c10bf3d4012aeffa8a",
+            "bfa86404a2e9ffe67d47c587ef7a97a7f456b863b4d02cfc6928973ab5b1cb39",
+            "976d3a4e9d23326dc0baa9fa560b7c4e53f42864f508483a6473b6a11079b2db",
+            "1b766e9ceb71ba6c01dcd46e0af462cd4cfa652ae5017d4555b8eeefe36e1932",
+        ),
+    ];
+
+    fn public_key(qx: &str, qy: &str) -> PublicKey<P256Params> {
+        let mut sec1 = vec![0x04];
+        sec1.extend(from_hex(qx));
+        sec1.extend(from_hex(qy));
+        PublicKey::from_sec1_bytes(&sec1).unwrap()
+    }
+
+    #[test]
+    fn test_p256_cavp_sig_gen() {
+        for (msg, d, qx, qy, r, s) in SIG_GEN {
+            let private_key = PrivateKey::<P256Params>::from_bytes(&from_hex(d)).unwrap();
+            let pub_key = PublicKey::new(&private_key).unwrap();
+            assert_eq!(pub_key, public_key(qx, qy));
+
+            let signature = Signature { r: scalar(r), s: scalar(s) };
+            let digest = Sha256::new_with_prefix(from_hex(msg));
+            assert_eq!(Signature::verify_digest(digest.clone(), &pub_key, &signature), Ok(()));
+
+            // Our nonces come from RFC 6979 rather than the vector's k, so
+            // only check that our own signature verifies.
+            let own = Signature::sign_digest(digest.clone(), &private_key).unwrap();
+            assert_eq!(Signature::verify_digest(digest, &pub_key, &own), Ok(()));
+        }
+    }
+
+    // FIPS 186-4 CAVP, SigVer.rsp, [P-256,SHA-256]: eight entries, passing and failing,
+    // with the result and reason as the file gives them.
+    const SIG_VER: [(&str, &str, &str, &str, &str, &str); 8] = [
+        (
+            "e4796db5f785f207aa30d311693b3702821dff1168fd2e04c0836825aefd850d9aa60326d88cde1a23c7745351392ca2288d632c264f197d05cd424a30336c19fd09bb229654f0222fcb881a4b35c290a093ac159ce13409111ff0358411133c24f5b8e2090d6db6558afc36f06ca1f6ef779785adba68db27a409859fc4c4a0",
+            "87f8f2b218f49845f6f10eec3877136269f5c1a54736dbdf69f89940cad41555",
+            "e15f369036f49842fac7a86c8a2b0557609776814448b8f5e84aa9f4395205e9",
+            "d19ff48b324915576416097d2544f7cbdf8768b1454ad20e0baac50e211f23b0",
+            "a3e81e59311cdfff2d4784949f7a2cb50ba6c3a91fa54710568e61aca3e847c6",
+            "F (3 - S changed)",
+        ),
+        (
+            "069a6e6b93dfee6df6ef6997cd80dd2182c36653cef10c655d524585655462d683877f95ecc6d6c81623d8fac4e900ed0019964094e7de91f1481989ae1873004565789cbf5dc56c62aedc63f62f3b894c9c6f7788c8ecaadc9bd0e81ad91b2b3569ea12260e93924fdddd3972af5273198f5efda0746219475017557616170e",
+            "5cf02a00d205bdfee2016f7421807fc38ae69e6b7ccd064ee689fc1a94a9f7d2",
+            "ec530ce3cc5c9d1af463f264d685afe2b4db4b5828d7e61b748930f3ce622a85",
+            "dc23d130c6117fb5751201455e99f36f59aba1a6a21cf2d0e7481a97451d6693",
+            "d6ce7708c18dbf35d4f8aa7240922dc6823f2e7058cbc1484fcad1599db5018c",
+            "F (2 - R changed)",
+        ),
+        (
+            "df04a346cf4d0e331a6db78cca2d456d31b0a000aa51441defdb97bbeb20b94d8d746429a393ba88840d661615e07def615a342abedfa4ce912e562af714959896858af817317a840dcff85a057bb91a3c2bf90105500362754a6dd321cdd86128cfc5f04667b57aa78c112411e42da304f1012d48cd6a7052d7de44ebcc01de",
+            "2ddfd145767883ffbb0ac003ab4a44346d08fa2570b3120dcce94562422244cb",
+            "5f70c7d11ac2b7a435ccfbbae02c3df1ea6b532cc0e9db74f93fffca7c6f9a64",
+            "9913111cff6f20c5bf453a99cd2c2019a4e749a49724a08774d14e4c113edda8",
+            "9467cd4cd21ecb56b0cab0a9a453b43386845459127a952421f5c6382866c5cc",
+            "F (4 - Q changed)",
+        ),
+        (
+            "e1130af6a38ccb412a9c8d13e15dbfc9e69a16385af3c3f1e5da954fd5e7c45fd75e2b8c36699228e92840c0562fbf3772f07e17f1add56588dd45f7450e1217ad239922dd9c32695dc71ff2424ca0dec1321aa47064a044b7fe3c2b97d03ce470a592304c5ef21eed9f93da56bb232d1eeb0035f9bf0dfafdcc4606272b20a3",
+            "e424dc61d4bb3cb7ef4344a7f8957a0c5134e16f7a67c074f82e6e12f49abf3c",
+            "970eed7aa2bc48651545949de1dddaf0127e5965ac85d1243d6f60e7dfaee927",
+            "bf96b99aa49c705c910be33142017c642ff540c76349b9dab72f981fd9347f4f",
+            "17c55095819089c2e03b9cd415abdf12444e323075d98f31920b9e0f57ec871c",
+            "P (0 )",
+        ),
+        (
+            "73c5f6a67456ae48209b5f85d1e7de7758bf235300c6ae2bdceb1dcb27a7730fb68c950b7fcada0ecc4661d3578230f225a875e69aaa17f1e71c6be5c831f22663bac63d0c7a9635edb0043ff8c6f26470f02a7bc56556f1437f06dfa27b487a6c4290d8bad38d4879b334e341ba092dde4e4ae694a9c09302e2dbf443581c08",
+            "e0fc6a6f50e1c57475673ee54e3a57f9a49f3328e743bf52f335e3eeaa3d2864",
+            "7f59d689c91e463607d9194d99faf316e25432870816dde63f5d4b373f12f22a",
+            "1d75830cd36f4c9aa181b2c4221e87f176b7f05b7c87824e82e396c88315c407",
+            "cb2acb01dac96efc53a32d4a0d85d0c2e48955214783ecf50a4f0414a319c05a",
+            "P (0 )",
+        ),
+        (
+            "666036d9b4a2426ed6585a4e0fd931a8761451d29ab04bd7dc6d0c5b9e38e6c2b263ff6cb837bd04399de3d757c6c7005f6d7a987063cf6d7e8cb38a4bf0d74a282572bd01d0f41e3fd066e3021575f0fa04f27b700d5b7ddddf50965993c3f9c7118ed78888da7cb221849b3260592b8e632d7c51e935a0ceae15207bedd548",
+            "a849bef575cac3c6920fbce675c3b787136209f855de19ffe2e8d29b31a5ad86",
+            "bf5fe4f7858f9b805bd8dcc05ad5e7fb889de2f822f3d8b41694e6c55c16b471",
+            "25acc3aa9d9e84c7abf08f73fa4195acc506491d6fc37cb9074528a7db87b9d6",
+            "9b21d5b5259ed3f2ef07dfec6cc90d3a37855d1ce122a85ba6a333f307d31537",
+            "F (2 - R changed)",
+        ),
+        (
+            "7e80436bce57339ce8da1b5660149a20240b146d108deef3ec5da4ae256f8f894edcbbc57b34ce37089c0daa17f0c46cd82b5a1599314fd79d2fd2f446bd5a25b8e32fcf05b76d644573a6df4ad1dfea707b479d97237a346f1ec632ea5660efb57e8717a8628d7f82af50a4e84b11f21bdff6839196a880ae20b2a0918d58cd",
+            "3dfb6f40f2471b29b77fdccba72d37c21bba019efa40c1c8f91ec405d7dcc5df",
+            "f22f953f1e395a52ead7f3ae3fc47451b438117b1e04d613bc8555b7d6e6d1bb",
+            "548886278e5ec26bed811dbb72db1e154b6f17be70deb1b210107decb1ec2a5a",
+            "e93bfebd2f14f3d827ca32b464be6e69187f5edbd52def4f96599c37d58eee75",
+            "F (4 - Q changed)",
+        ),
+        (
+            "1669bfb657fdc62c3ddd63269787fc1c969f1850fb04c933dda063ef74a56ce13e3a649700820f0061efabf849a85d474326c8a541d99830eea8131eaea584f22d88c353965dabcdc4bf6b55949fd529507dfb803ab6b480cd73ca0ba00ca19c438849e2cea262a1c57d8f81cd257fb58e19dec7904da97d8386e87b84948169",
+            "69b7667056e1e11d6caf6e45643f8b21e7a4bebda463c7fdbc13bc98efbd0214",
+            "d3f9b12eb46c7c6fda0da3fc85bc1fd831557f9abc902a3be3cb3e8be7d1aa2f",
+            "288f7a1cd391842cce21f00e6f15471c04dc182fe4b14d92dc18910879799790",
+            "247b3c4e89a3bcadfea73c7bfd361def43715fa382b8c3edf4ae15d6e55e9979",
+            "F (1 - Message changed)",
+        ),
+    ];
+
+    #[test]
+    fn test_p256_cavp_sig_ver() {
+        for (msg, qx, qy, r, s, result) in SIG_VER {
+            let signature = Signature::<P256Params> { r: scalar(r), s: scalar(s) };
+            let digest = Sha256::new_with_prefix(from_hex(msg));
+            let verified = Signature::verify_digest(digest, &public_key(qx, qy), &signature);
+            if result.starts_with('P') {
+                assert_eq!(verified, Ok(()), "{}", msg);
+            } else {
+                assert_eq!(verified, Err(Error::InvalidSignature), "{}: {}", msg, result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_p256_off_curve_key() {
+        let (_, _, qx, _, _, _) = SIG_GEN[0];
+        let (_, _, _, other_qy, _, _) = SIG_GEN[1];
+        let mut off_curve = vec![0x04];
+        off_curve.extend(from_hex(qx));
+        off_curve.extend(from_hex(other_qy));
+        assert_eq!(PublicKey::<P256Params>::from_sec1_bytes(&off_curve), Err(Error::PointNotOnCurve));
+    }
+
+    // RFC 6979, appendix A.2.5: P-256 with SHA-256. The signer normalises to
+    // low S, so the published s may come back as n - s.
+    #[test]
+    fn test_p256_rfc6979_signatures() {
+        let x = from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let private_key = PrivateKey::<P256Params>::from_bytes(&x).unwrap();
+        let pub_key = PublicKey::new(&private_key).unwrap();
+        assert_eq!(
+            pub_key,
+            public_key(
+                "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+                "7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+            )
+        );
+        let vectors = [
+            (
+                "sample",
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+                "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+            ),
+            (
+                "test",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+                "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+            ),
+        ];
+        for (msg, r, s) in vectors {
+            let signature = Signature::sign_message(msg, &private_key).unwrap();
+            assert_eq!(signature.r, scalar(r));
+            assert!(signature.s == scalar(s) || signature.s == -scalar(s));
+            assert!(signature.is_low_s());
+            assert_eq!(Signature::validate(msg, &pub_key, &signature), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_p256_sign_and_verify() {
+        let private_key = PrivateKey::<P256Params>::generate();
+        let pub_key = PublicKey::new(&private_key).unwrap();
+        let signature = Signature::sign_message("webauthn", &private_key).unwrap();
+        assert_eq!(Signature::validate("webauthn", &pub_key, &signature), Ok(()));
+        assert_eq!(Signature::validate("webauthm", &pub_key, &signature), Err(Error::InvalidSignature));
+
+        let compressed = pub_key.to_sec1_bytes(true);
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(PublicKey::from_sec1_bytes(&compressed), Ok(pub_key));
+        let decoded = Signature::<P256Params>::from_der(&signature.to_der()).unwrap();
+        assert_eq!((decoded.r, decoded.s), (signature.r, signature.s));
+    }
+}
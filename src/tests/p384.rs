@@ -0,0 +1,265 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt, Sign};
+    use sha2::{Digest, Sha384, Sha512};
+
+    use crate::{
+        arithmetic::Modular,
+        curve::{CurveParams, Field, ScalarField},
+        ecdsa::{hash_to_scalar, PrivateKey, PublicKey, Signature},
+        error::Error,
+        secp256k1::{P384FieldElement, P384Params, P384Scalar},
+    };
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn scalar(s: &str) -> P384Scalar {
+        P384Scalar::from_be_bytes(&from_hex(s)).unwrap()
+    }
+
+    // RFC 6979, appendix A.2.6.
+    const X: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+    const UX: &str = "ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254515480bc13";
+    const UY: &str = "8015d9b72d7d57244ea8ef9ac0c621896708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720";
+
+    fn rfc6979_key() -> (PrivateKey<P384Params>, PublicKey<P384Params>) {
+        let private_key = PrivateKey::from_bytes(&from_hex(X)).unwrap();
+        let public_key = PublicKey::new(&private_key).unwrap();
+        (private_key, public_key)
+    }
+
+    #[test]
+    fn test_p384_field_and_scalar_ops_match_bigint() {
+        let params = P384Params::get();
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let a = rng.gen_bigint_range(&BigInt::from(1), &params.p);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &params.p);
+            let (fa, fb) = (P384FieldElement::from_bigint(&a).unwrap(), P384FieldElement::from_bigint(&b).unwrap());
+            assert_eq!((fa + fb).to_bigint(), (&a + &b).modulus(&params.p));
+            assert_eq!((fa - fb).to_bigint(), (&a - &b).modulus(&params.p));
+            assert_eq!((fa * fb).to_bigint(), (&a * &b).modulus(&params.p));
+            assert_eq!(fa.invert().unwrap().to_bigint(), a.invmod(&params.p).unwrap());
+
+            let a = rng.gen_bigint_range(&BigInt::from(1), &params.n);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &params.n);
+            let (sa, sb) = (P384Scalar::from_bigint(&a).unwrap(), P384Scalar::from_bigint(&b).unwrap());
+            assert_eq!((sa * sb).to_bigint(), (&a * &b).modulus(&params.n));
+            assert_eq!(sa.invert().unwrap().to_bigint(), a.invmod(&params.n).unwrap());
+        }
+        assert_eq!(params.g.times(&-P384Scalar::ONE), -params.g.clone());
+    }
+
+    #[test]
+    fn test_p384_rfc6979_signatures() {
+        let (private_key, public_key) = rfc6979_key();
+        let mut sec1 = vec![0x04];
+        sec1.extend(from_hex(UX));
+        sec1.extend(from_hex(UY));
+        assert_eq!(public_key.to_sec1_bytes(false), sec1);
+
+        // SHA-384, the curve's default message digest.
+        let vectors = [
+            (
+                "sample",
+                "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46",
+                "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+            ),
+            (
+                "test",
+                "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb0542a7f0812998da8f1dd3ca3cf023db",
+                "ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e06a739f040649a667bf3b828246baa5a5",
+            ),
+        ];
+        for (msg, r, s) in vectors {
+            let signature = Signature::sign_message(msg, &private_key).unwrap();
+            assert_eq!(signature.r, scalar(r));
+            assert!(signature.s == scalar(s) || signature.s == -scalar(s));
+            assert_eq!(Signature::validate(msg, &public_key, &signature), Ok(()));
+            let published = Signature { r: scalar(r), s: scalar(s) };
+            assert_eq!(Signature::validate(msg, &public_key, &published), Ok(()));
+        }
+    }
+
+    // SHA-512 is longer than n, so the digest has to be truncated to 384 bits.
+    #[test]
+    fn test_p384_truncated_digest() {
+        let (private_key, public_key) = rfc6979_key();
+        let digest = Sha512::new_with_prefix("sample");
+        let signature = Signature::sign_digest(digest.clone(), &private_key).unwrap();
+        assert_eq!(
+            signature.r,
+            scalar("ed0959d5880ab2d869ae7f6c2915c6d60f96507f9cb3e047c0046861da4a799cfe30f35cc900056d7c99cd7882433709")
+        );
+        let s = scalar("512c8cceee3890a84058ce1e22dbc2198f42323ce8aca9135329f03c068e5112dc7cc3ef3446defceb01a45c2667fdd5");
+        assert!(signature.s == s || signature.s == -s);
+        assert_eq!(Signature::verify_digest(digest.clone(), &public_key, &signature), Ok(()));
+
+        let hash = digest.finalize();
+        let expected = BigInt::from_bytes_be(Sign::Plus, &hash[..48]).modulus(&P384Params::get().n);
+        assert_eq!(hash_to_scalar::<P384Params>(&hash).to_bigint(), expected);
+        assert_eq!(Signature::verify_prehashed(&hash, &public_key, &signature), Ok(()));
+    }
+
+    #[test]
+    fn test_p384_rejects_invalid_signatures() {
+        let (private_key, public_key) = rfc6979_key();
+        let signature = Signature::sign_message("sample", &private_key).unwrap();
+        let swapped = Signature { r: signature.s, s: signature.r };
+        assert_eq!(Signature::validate("sample", &public_key, &swapped), Err(Error::InvalidSignature));
+        let zero_r = Signature { r: P384Scalar::ZERO, s: signature.s };
+        assert_eq!(Signature::validate("sample", &public_key, &zero_r), Err(Error::InvalidScalar));
+        assert_eq!(Signature::validate("Sample", &public_key, &signature), Err(Error::InvalidSignature));
+
+        let compact = signature.to_compact();
+        assert_eq!(compact.len(), 96);
+        let parsed = Signature::<P384Params>::from_compact(&compact).unwrap();
+        assert_eq!((parsed.r, parsed.s), (signature.r, signature.s));
+        assert_eq!(Signature::<P384Params>::from_compact(&compact[..64]).unwrap_err(), Error::InvalidEncoding);
+    }
+
+    // Wycheproof, ecdsa_secp384r1_sha384_test.json, as shipped in the
+    // RustCrypto p384 0.13.1 crate's wycheproof.blb: every case the suite marks
+    // valid or invalid, including the special-value, extreme-k and point
+    // duplication groups. The blob drops tcId, comment and flags, so rows
+    // are numbered by their position in it.
+    const WYCHEPROOF: &str = include_str!("wycheproof_secp384r1_sha384.csv");
+
+    #[test]
+    fn test_p384_wycheproof() {
+        for line in WYCHEPROOF.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let [case, wx, wy, msg, sig, result] = fields[..] else {
+                panic!("malformed vector: {}", line);
+            };
+            let mut sec1 = vec![0x04];
+            sec1.extend(from_hex(wx));
+            sec1.extend(from_hex(wy));
+            let verified = PublicKey::<P384Params>::from_sec1_bytes(&sec1).and_then(|public_key| {
+                let signature = Signature::<P384Params>::from_der(&from_hex(sig))?;
+                Signature::verify_digest(Sha384::new_with_prefix(from_hex(msg)), &public_key, &signature)
+            });
+            assert_eq!(verified.is_ok(), result == "valid", "case {}: {:?}", case, verified);
+        }
+    }
+
+    // Signatures built around corner cases of the verification equation
+    // u1·G + u2·P: the extreme nonces k = 1 and k = n - 1, e = 0 (u1 = 0), a key
+    // for which s = r (u2 = 1), one for which u1·G = u2·P, so the sum is a
+    // point doubling, and one for which u1·G = -u2·P, so the sum is the point
+    // at infinity and nothing verifies.
+    #[test]
+    fn test_p384_arithmetic_edge_cases() {
+        let sign = |k: P384Scalar, x: P384Scalar, e: P384Scalar| {
+            let r_x = P384Params::mul_generator(&k).to_affine().x.unwrap();
+            let r = P384Scalar::from_be_bytes_reduced(&r_x.to_be_bytes());
+            Signature::<P384Params> { r, s: (e + r * x) * k.invert().unwrap() }
+        };
+        let key = |x: P384Scalar| PublicKey::new(&PrivateKey::from_bytes(&x.to_be_bytes()).unwrap()).unwrap();
+        let hash = Sha384::digest("edge cases");
+        let e = hash_to_scalar::<P384Params>(&hash);
+        let x = scalar(X);
+
+        for k in [P384Scalar::ONE, -P384Scalar::ONE] {
+            assert_eq!(Signature::verify_prehashed(&hash, &key(x), &sign(k, x, e)), Ok(()));
+        }
+        let k = P384Scalar::from_u64(0x5eed);
+        let zero = [0u8; 48];
+        assert_eq!(Signature::verify_prehashed(&zero, &key(x), &sign(k, x, P384Scalar::ZERO)), Ok(()));
+
+        let r = sign(k, x, e).r;
+        let e_over_r = e * r.invert().unwrap();
+        let signature = sign(k, k - e_over_r, e);
+        assert_eq!(signature.s, signature.r);
+        assert_eq!(Signature::verify_prehashed(&hash, &key(k - e_over_r), &signature), Ok(()));
+        assert_eq!(Signature::verify_prehashed(&hash, &key(e_over_r), &sign(k, e_over_r, e)), Ok(()));
+
+        let signature = Signature::<P384Params> { r, s: k };
+        assert_eq!(Signature::verify_prehashed(&hash, &key(-e_over_r), &signature), Err(Error::InvalidSignature));
+    }
+
+    fn public_key(qx: &str, qy: &str) -> PublicKey<P384Params> {
+        let mut sec1 = vec![0x04];
+        sec1.extend(from_hex(qx));
+        sec1.extend(from_hex(qy));
+        PublicKey::from_sec1_bytes(&sec1).unwrap()
+    }
+
+    // FIPS 186-4 CAVP, SigGen.txt, [P-384,SHA-384]: the first three entries,
+    // as digests, from BoringSSL's ecdsa_sign_tests.txt. Fields are the
+    // digest, d, Qx, Qy, k, R and S.
+    const SIG_GEN: [(&str, &str, &str, &str, &str, &str, &str); 3] = [
+        (
+            "31a452d6164d904bb5724c878280231eae705c29ce9d4bc7d58e020e1085f17eebcc1a38f0ed0bf2b344d81fbd896825",
+            "201b432d8df14324182d6261db3e4b3f46a8284482d52e370da41e6cbdf45ec2952f5db7ccbce3bc29449f4fb080ac97",
+            "c2b47944fb5de342d03285880177ca5f7d0f2fcad7678cce4229d6e1932fcac11bfc3c3e97d942a3c56bf34123013dbf",
+            "37257906a8223866eda0743c519616a76a758ae58aee81c5fd35fbf3a855b7754a36d4a0672df95d6c44a81cf7620c2d",
+            "dcedabf85978e090f733c6e16646fa34df9ded6e5ce28c6676a00f58a25283db8885e16ce5bf97f917c81e1f25c9c771",
+            "50835a9251bad008106177ef004b091a1e4235cd0da84fff54542b0ed755c1d6f251609d14ecf18f9e1ddfe69b946e32",
+            "0475f3d30c6463b646e8d3bf2455830314611cbde404be518b14464fdb195fdcc92eb222e61f426a4a592c00a6a89721",
+        ),
+        (
+            "a92784916a40feaebfeab16ea28c0c65e45c5e81eb634052944865708072e20110bd669a9838d7e722e94ac75245cdd3",
+            "23d9f4ea6d87b7d6163d64256e3449255db14786401a51daa7847161bf56d494325ad2ac8ba928394e01061d882c3528",
+            "5d42d6301c54a438f65970bae2a098cbc567e98840006e356221966c86d82e8eca515bca850eaa3cd41f175f03a0cbfd",
+            "4aef5a0ceece95d382bd70ab5ce1cb77408bae42b51a08816d5e5e1d3da8c18fcc95564a752730b0aabea983ccea4e2e",
+            "67ba379366049008593eac124f59ab017358892ee0c063d38f3758bb849fd25d867c3561563cac1532a323b228dc0890",
+            "fb318f4cb1276282bb43f733a7fb7c567ce94f4d02924fc758635ab2d1107108bf159b85db080cdc3b30fbb5400016f3",
+            "588e3d7af5da03eae255ecb1813100d95edc243476b724b22db8e85377660d7645ddc1c2c2ee4eaea8b683dbe22f86ca",
+        ),
+        (
+            "b2acf6b4ae1ba9985c1e657313d59157939c21868302f6f5c5dbf037867035ae7c2009bad9fce472579923f7b4b87795",
+            "b5f670e98d8befc46f6f51fb2997069550c2a52ebfb4e5e25dd905352d9ef89eed5c2ecd16521853aadb1b52b8c42ae6",
+            "44ffb2a3a95e12d87c72b5ea0a8a7cb89f56b3bd46342b2303608d7216301c21b5d2921d80b6628dc512ccb84e2fc278",
+            "e4c1002f1828abaec768cadcb7cf42fbf93b1709ccae6df5b134c41fae2b9a188bfbe1eccff0bd348517d7227f2071a6",
+            "229e67638f712f57bea4c2b02279d5ccad1e7c9e201c77f6f01aeb81ea90e62b44b2d2107fd66d35e56608fff65e28e4",
+            "b11db592e4ebc75b6472b879b1d8ce57452c615aef20f67a280f8bca9b11a30ad4ac9d69541258c7dd5d0b4ab8dd7d49",
+            "4eb51db8004e46d438359abf060a9444616cb46b4f99c9a05b53ba6df02e914c9c0b6cc3a9791d804d2e4c0984dab1cc",
+        ),
+    ];
+
+    // With the vector's k in hand, R and S are known answers rather than
+    // just signatures that verify.
+    #[test]
+    fn test_p384_cavp_sig_gen() {
+        for (digest, d, qx, qy, k, r, s) in SIG_GEN {
+            let private_key = PrivateKey::<P384Params>::from_bytes(&from_hex(d)).unwrap();
+            let pub_key = PublicKey::new(&private_key).unwrap();
+            assert_eq!(pub_key, public_key(qx, qy));
+
+            let k = scalar(k);
+            let r_x = P384Params::mul_generator(&k).to_affine().x.unwrap();
+            let expected_r = P384Scalar::from_be_bytes_reduced(&r_x.to_be_bytes());
+            assert_eq!(expected_r, scalar(r));
+            let e = hash_to_scalar::<P384Params>(&from_hex(digest));
+            assert_eq!((e + expected_r * scalar(d)) * k.invert().unwrap(), scalar(s));
+
+            let signature = Signature { r: scalar(r), s: scalar(s) };
+            assert_eq!(Signature::verify_prehashed(&from_hex(digest), &pub_key, &signature), Ok(()));
+        }
+    }
+
+    // FIPS 186-4 CAVP, SigVer.rsp, [P-384,SHA-384]: all fifteen entries, as
+    // digests, from BoringSSL's ecdsa_verify_tests.txt. That file keeps
+    // only the P/F result, not SigVer.rsp's failure reason.
+    const SIG_VER: &str = include_str!("cavp_sig_ver_p384.csv");
+
+    #[test]
+    fn test_p384_cavp_sig_ver() {
+        for (index, line) in SIG_VER.lines().skip(1).enumerate() {
+            let fields: Vec<&str> = line.split(',').collect();
+            let [qx, qy, digest, r, s, result] = fields[..] else {
+                panic!("malformed vector: {}", line);
+            };
+            let signature = Signature::<P384Params> { r: scalar(r), s: scalar(s) };
+            let verified = Signature::verify_prehashed(&from_hex(digest), &public_key(qx, qy), &signature);
+            if result == "P" {
+                assert_eq!(verified, Ok(()), "entry {}", index);
+            } else {
+                assert_eq!(verified, Err(Error::InvalidSignature), "entry {}", index);
+            }
+        }
+    }
+}
@@ -0,0 +1,265 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt, Sign};
+    use sha2::{Digest, Sha256, Sha512};
+
+    use crate::{
+        arithmetic::Modular,
+        curve::{CurveParams, Field, ScalarField},
+        ecdsa::{hash_to_scalar, recover_public_key, PrivateKey, PublicKey, RecoverableSignature, Signature},
+        error::{DerError, Error},
+        secp256k1::{P521FieldElement, P521Params, P521Scalar},
+    };
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn scalar(s: &str) -> P521Scalar {
+        P521Scalar::from_be_bytes(&from_hex(s)).unwrap()
+    }
+
+    // RFC 6979, appendix A.2.7.
+    const X: &str = "00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538";
+    const UX: &str = "01894550d0785932e00eaa23b694f213f8c3121f86dc97a04e5a7167db4e5bcd371123d46e45db6b5d5370a7f20fb633155d38ffa16d2bd761dcac474b9a2f5023a4";
+    const UY: &str = "00493101c962cd4d2fddf782285e64584139c2f91b47f87ff82354d6630f746a28a0db25741b5b34a828008b22acc23f924faafbd4d33f81ea66956dfeaa2bfdfcf5";
+
+    fn rfc6979_key() -> (PrivateKey<P521Params>, PublicKey<P521Params>) {
+        let private_key = PrivateKey::from_bytes(&from_hex(X)).unwrap();
+        let public_key = PublicKey::new(&private_key).unwrap();
+        (private_key, public_key)
+    }
+
+    #[test]
+    fn test_p521_field_and_scalar_ops_match_bigint() {
+        let params = P521Params::get();
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let a = rng.gen_bigint_range(&BigInt::from(1), &params.p);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &params.p);
+            let (fa, fb) = (P521FieldElement::from_bigint(&a).unwrap(), P521FieldElement::from_bigint(&b).unwrap());
+            assert_eq!((fa + fb).to_bigint(), (&a + &b).modulus(&params.p));
+            assert_eq!((fa - fb).to_bigint(), (&a - &b).modulus(&params.p));
+            assert_eq!((fa * fb).to_bigint(), (&a * &b).modulus(&params.p));
+            assert_eq!(fa.invert().unwrap().to_bigint(), a.invmod(&params.p).unwrap());
+            let root = fa.square().sqrt().unwrap();
+            assert!(root == fa || root == -fa);
+
+            let a = rng.gen_bigint_range(&BigInt::from(1), &params.n);
+            let b = rng.gen_bigint_range(&BigInt::from(0), &params.n);
+            let (sa, sb) = (P521Scalar::from_bigint(&a).unwrap(), P521Scalar::from_bigint(&b).unwrap());
+            assert_eq!((sa * sb).to_bigint(), (&a * &b).modulus(&params.n));
+            assert_eq!(sa.invert().unwrap().to_bigint(), a.invmod(&params.n).unwrap());
+        }
+        assert_eq!(params.g.times(&-P521Scalar::ONE), -params.g.clone());
+        assert!(P521Scalar::random(&mut rng).to_bigint() < params.n);
+    }
+
+    #[test]
+    fn test_p521_rfc6979_signatures() {
+        let (private_key, public_key) = rfc6979_key();
+        let mut sec1 = vec![0x04];
+        sec1.extend(from_hex(UX));
+        sec1.extend(from_hex(UY));
+        assert_eq!(public_key.to_sec1_bytes(false), sec1);
+        assert_eq!(public_key.to_sec1_bytes(true).len(), 67);
+
+        // SHA-512, the curve's default message digest.
+        let signature = Signature::sign_message("sample", &private_key).unwrap();
+        assert_eq!(
+            signature.r,
+            scalar("00c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e377fa")
+        );
+        let s = scalar("00617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e44da4a67a");
+        assert!(signature.s == s || signature.s == -s);
+        assert_eq!(Signature::validate("sample", &public_key, &signature), Ok(()));
+        assert_eq!(Signature::validate("sample", &public_key, &Signature { r: signature.r, s }), Ok(()));
+    }
+
+    // SHA-256 is shorter than n and is used as is.
+    #[test]
+    fn test_p521_short_digest() {
+        let (private_key, public_key) = rfc6979_key();
+        let digest = Sha256::new_with_prefix("sample");
+        let signature = Signature::sign_digest(digest.clone(), &private_key).unwrap();
+        assert_eq!(
+            signature.r,
+            scalar("01511bb4d675114fe266fc4372b87682baecc01d3cc62cf2303c92b3526012659d16876e25c7c1e57648f23b73564d67f61c6f14d527d54972810421e7d87589e1a7")
+        );
+        let s = scalar("004a171143a83163d6df460aaf61522695f207a58b95c0644d87e52aa1a347916e4f7a72930b1bc06dbe22ce3f58264afd23704cbb63b29b931f7de6c9d949a7ecfc");
+        assert!(signature.s == s || signature.s == -s);
+        assert_eq!(Signature::verify_digest(digest, &public_key, &signature), Ok(()));
+    }
+
+    #[test]
+    fn test_p521_hash_to_scalar() {
+        // A 66-byte digest keeps its leftmost 521 bits.
+        let hash = [0xffu8; 66];
+        let expected: BigInt = BigInt::from_bytes_be(Sign::Plus, &hash) >> 7;
+        assert_eq!(hash_to_scalar::<P521Params>(&hash).to_bigint(), expected.modulus(&P521Params::get().n));
+        let short = [0xabu8; 32];
+        assert_eq!(hash_to_scalar::<P521Params>(&short).to_bigint(), BigInt::from_bytes_be(Sign::Plus, &short));
+    }
+
+    #[test]
+    fn test_p521_der_and_recovery() {
+        let private_key = PrivateKey::<P521Params>::generate();
+        let public_key = PublicKey::new(&private_key).unwrap();
+        let signature = RecoverableSignature::sign_message("recover me", &private_key).unwrap();
+        let hash = sha2::Sha512::digest("recover me");
+        assert_eq!(recover_public_key(&hash, &signature), Ok(public_key));
+
+        let signature = Signature::from(signature);
+        let der = signature.to_der();
+        assert_eq!(&der[..2], &[0x30, 0x81]);
+        let parsed = Signature::<P521Params>::from_der(&der).unwrap();
+        assert_eq!((parsed.r, parsed.s), (signature.r, signature.s));
+
+        let mut trailing = der.clone();
+        trailing.push(0);
+        assert_eq!(Signature::<P521Params>::from_der(&trailing).unwrap_err(), Error::Der(DerError::TrailingData));
+    }
+
+    // Wycheproof, ecdsa_secp521r1_sha512_test.json, as shipped in the
+    // RustCrypto p521 0.13.3 crate's wycheproof.blb: every case the suite marks
+    // valid or invalid, including the special-value, extreme-k and point
+    // duplication groups. The blob drops tcId, comment and flags, so rows
+    // are numbered by their position in it.
+    const WYCHEPROOF: &str = include_str!("wycheproof_secp521r1_sha512.csv");
+
+    #[test]
+    fn test_p521_wycheproof() {
+        for line in WYCHEPROOF.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let [case, wx, wy, msg, sig, result] = fields[..] else {
+                panic!("malformed vector: {}", line);
+            };
+            let mut sec1 = vec![0x04];
+            sec1.extend(from_hex(wx));
+            sec1.extend(from_hex(wy));
+            let verified = PublicKey::<P521Params>::from_sec1_bytes(&sec1).and_then(|public_key| {
+                let signature = Signature::<P521Params>::from_der(&from_hex(sig))?;
+                Signature::verify_digest(Sha512::new_with_prefix(from_hex(msg)), &public_key, &signature)
+            });
+            assert_eq!(verified.is_ok(), result == "valid", "case {}: {:?}", case, verified);
+        }
+    }
+
+    // Signatures built around corner cases of the verification equation
+    // u1·G + u2·P: the extreme nonces k = 1 and k = n - 1, e = 0 (u1 = 0), a key
+    // for which s = r (u2 = 1), one for which u1·G = u2·P, so the sum is a
+    // point doubling, and one for which u1·G = -u2·P, so the sum is the point
+    // at infinity and nothing verifies.
+    #[test]
+    fn test_p521_arithmetic_edge_cases() {
+        let sign = |k: P521Scalar, x: P521Scalar, e: P521Scalar| {
+            let r_x = P521Params::mul_generator(&k).to_affine().x.unwrap();
+            let r = P521Scalar::from_be_bytes_reduced(&r_x.to_be_bytes());
+            Signature::<P521Params> { r, s: (e + r * x) * k.invert().unwrap() }
+        };
+        let key = |x: P521Scalar| PublicKey::new(&PrivateKey::from_bytes(&x.to_be_bytes()).unwrap()).unwrap();
+        let hash = Sha512::digest("edge cases");
+        let e = hash_to_scalar::<P521Params>(&hash);
+        let x = scalar(X);
+
+        for k in [P521Scalar::ONE, -P521Scalar::ONE] {
+            assert_eq!(Signature::verify_prehashed(&hash, &key(x), &sign(k, x, e)), Ok(()));
+        }
+        let k = P521Scalar::from_u64(0x5eed);
+        let zero = [0u8; 64];
+        assert_eq!(Signature::verify_prehashed(&zero, &key(x), &sign(k, x, P521Scalar::ZERO)), Ok(()));
+
+        let r = sign(k, x, e).r;
+        let e_over_r = e * r.invert().unwrap();
+        let signature = sign(k, k - e_over_r, e);
+        assert_eq!(signature.s, signature.r);
+        assert_eq!(Signature::verify_prehashed(&hash, &key(k - e_over_r), &signature), Ok(()));
+        assert_eq!(Signature::verify_prehashed(&hash, &key(e_over_r), &sign(k, e_over_r, e)), Ok(()));
+
+        let signature = Signature::<P521Params> { r, s: k };
+        assert_eq!(Signature::verify_prehashed(&hash, &key(-e_over_r), &signature), Err(Error::InvalidSignature));
+    }
+
+    fn public_key(qx: &str, qy: &str) -> PublicKey<P521Params> {
+        let mut sec1 = vec![0x04];
+        sec1.extend(from_hex(qx));
+        sec1.extend(from_hex(qy));
+        PublicKey::from_sec1_bytes(&sec1).unwrap()
+    }
+
+    // FIPS 186-4 CAVP, SigGen.txt, [P-521,SHA-512]: the first three entries,
+    // as digests, from BoringSSL's ecdsa_sign_tests.txt. Fields are the
+    // digest, d, Qx, Qy, k, R and S.
+    const SIG_GEN: [(&str, &str, &str, &str, &str, &str, &str); 3] = [
+        (
+            "65f83408092261bda599389df03382c5be01a81fe00a36f3f4bb6541263f801627c440e50809712b0cace7c217e6e5051af81de9bfec3204dcd63c4f9a741047",
+            "00f749d32704bc533ca82cef0acf103d8f4fba67f08d2678e515ed7db886267ffaf02fab0080dca2359b72f574ccc29a0f218c8655c0cccf9fee6c5e567aa14cb926",
+            "0061387fd6b95914e885f912edfbb5fb274655027f216c4091ca83e19336740fd81aedfe047f51b42bdf68161121013e0d55b117a14e4303f926c8debb77a7fdaad1",
+            "00e7d0c75c38626e895ca21526b9f9fdf84dcecb93f2b233390550d2b1463b7ee3f58df7346435ff0434199583c97c665a97f12f706f2357da4b40288def888e59e6",
+            "003af5ab6caa29a6de86a5bab9aa83c3b16a17ffcd52b5c60c769be3053cdddeac60812d12fecf46cfe1f3db9ac9dcf881fcec3f0aa733d4ecbb83c7593e864c6df1",
+            "004de826ea704ad10bc0f7538af8a3843f284f55c8b946af9235af5af74f2b76e099e4bc72fd79d28a380f8d4b4c919ac290d248c37983ba05aea42e2dd79fdd33e8",
+            "0087488c859a96fea266ea13bf6d114c429b163be97a57559086edb64aed4a18594b46fb9efc7fd25d8b2de8f09ca0587f54bd287299f47b2ff124aac566e8ee3b43",
+        ),
+        (
+            "a6200971c6a289e2fcb80f78ec08a5079ea2675efd68bcab479552aa5bcb8edf3c993c79d7cebcc23c20e5af41723052b871134cc71d5c57206182a7068cc39b",
+            "01a4d2623a7d59c55f408331ba8d1523b94d6bf8ac83375ceb57a2b395a5bcf977cfc16234d4a97d6f6ee25a99aa5bff15ff535891bcb7ae849a583e01ac49e0e9b6",
+            "004d5c8afee038984d2ea96681ec0dccb6b52dfa4ee2e2a77a23c8cf43ef19905a34d6f5d8c5cf0981ed804d89d175b17d1a63522ceb1e785c0f5a1d2f3d15e51352",
+            "0014368b8e746807b2b68f3615cd78d761a464ddd7918fc8df51d225962fdf1e3dc243e265100ff0ec133359e332e44dd49afd8e5f38fe86133573432d33c02fa0a3",
+            "00bc2c0f37155859303de6fa539a39714e195c37c6ea826e224c8218584ae09cd0d1cc14d94d93f2d83c96e4ef68517fdb3f383da5404e5a426bfc5d424e253c181b",
+            "01a3c4a6386c4fb614fba2cb9e74201e1aaa0001aa931a2a939c92e04b8344535a20f53c6e3c69c75c2e5d2fe3549ed27e6713cb0f4a9a94f6189eb33bff7d453fce",
+            "016a997f81aa0bea2e1469c8c1dab7df02a8b2086ba482c43af04f2174831f2b1761658795adfbdd44190a9b06fe10e578987369f3a2eced147cff89d8c2818f7471",
+        ),
+        (
+            "46ff533622cc90321a3aeb077ec4db4fbf372c7a9db48b59de7c5d59e6314110676ba5491bd20d0f02774eef96fc2e88ca99857d21ef255184c93fb1ff4f01d3",
+            "014787f95fb1057a2f3867b8407e54abb91740c097dac5024be92d5d65666bb16e4879f3d3904d6eab269cf5e7b632ab3c5f342108d1d4230c30165fba3a1bf1c66f",
+            "00c2d540a7557f4530de35bbd94da8a6defbff783f54a65292f8f76341c996cea38795805a1b97174a9147a8644282e0d7040a6f83423ef2a0453248156393a1782e",
+            "0119f746c5df8cec24e4849ac1870d0d8594c799d2ceb6c3bdf891dfbd2242e7ea24d6aec3166214734acc4cbf4da8f71e2429c5c187b2b3a048527c861f58a9b97f",
+            "0186cd803e6e0c9925022e41cb68671adba3ead5548c2b1cd09348ab19612b7af3820fd14da5fe1d7b550ed1a3c8d2f30592cd7745a3c09ee7b5dcfa9ed31bdd0f1f",
+            "010ed3ab6d07a15dc3376494501c27ce5f78c8a2b30cc809d3f9c3bf1aef437e590ef66abae4e49065ead1af5f752ec145acfa98329f17bca9991a199579c41f9229",
+            "008c3457fe1f93d635bb52df9218bf3b49a7a345b8a8a988ac0a254340546752cddf02e6ce47eee58ea398fdc9130e55a4c09f5ae548c715f5bcd539f07a34034d78",
+        ),
+    ];
+
+    // With the vector's k in hand, R and S are known answers rather than
+    // just signatures that verify.
+    #[test]
+    fn test_p521_cavp_sig_gen() {
+        for (digest, d, qx, qy, k, r, s) in SIG_GEN {
+            let private_key = PrivateKey::<P521Params>::from_bytes(&from_hex(d)).unwrap();
+            let pub_key = PublicKey::new(&private_key).unwrap();
+            assert_eq!(pub_key, public_key(qx, qy));
+
+            let k = scalar(k);
+            let r_x = P521Params::mul_generator(&k).to_affine().x.unwrap();
+            let expected_r = P521Scalar::from_be_bytes_reduced(&r_x.to_be_bytes());
+            assert_eq!(expected_r, scalar(r));
+            let e = hash_to_scalar::<P521Params>(&from_hex(digest));
+            assert_eq!((e + expected_r * scalar(d)) * k.invert().unwrap(), scalar(s));
+
+            let signature = Signature { r: scalar(r), s: scalar(s) };
+            assert_eq!(Signature::verify_prehashed(&from_hex(digest), &pub_key, &signature), Ok(()));
+        }
+    }
+
+    // FIPS 186-4 CAVP, SigVer.rsp, [P-521,SHA-512]: all fifteen entries, as
+    // digests, from BoringSSL's ecdsa_verify_tests.txt. That file keeps
+    // only the P/F result, not SigVer.rsp's failure reason.
+    const SIG_VER: &str = include_str!("cavp_sig_ver_p521.csv");
+
+    #[test]
+    fn test_p521_cavp_sig_ver() {
+        for (index, line) in SIG_VER.lines().skip(1).enumerate() {
+            let fields: Vec<&str> = line.split(',').collect();
+            let [qx, qy, digest, r, s, result] = fields[..] else {
+                panic!("malformed vector: {}", line);
+            };
+            let signature = Signature::<P521Params> { r: scalar(r), s: scalar(s) };
+            let verified = Signature::verify_prehashed(&from_hex(digest), &public_key(qx, qy), &signature);
+            if result == "P" {
+                assert_eq!(verified, Ok(()), "entry {}", index);
+            } else {
+                assert_eq!(verified, Err(Error::InvalidSignature), "entry {}", index);
+            }
+        }
+    }
+}
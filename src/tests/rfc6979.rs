@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, Sign};
+    use sha2::{Digest, Sha256};
+
+    use crate::{curve::CurveParams, rfc6979::{generate_k, Rfc6979}, scalar::Scalar, secp256k1::{PrivateKey, Secp256k1Params, Signature}};
+
+    fn hex(s: &str) -> BigInt {
+        BigInt::parse_bytes(s.as_bytes(), 16).unwrap()
+    }
+
+    fn int(k: &[u8]) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, k)
+    }
+
+    fn octets(s: &str) -> Vec<u8> {
+        hex(s).to_bytes_be().1
+    }
+
+    // RFC 6979, appendix A.1: ansix9t163k1, SHA-256, message "sample".
+    #[test]
+    fn test_rfc6979_detailed_example() {
+        let q = hex("4000000000000000000020108A2E0CC0D99F8A5EF");
+        let x = octets("09A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+        let h1 = Sha256::digest(b"sample");
+        let k = generate_k::<Sha256>(&q, &x, &h1, None);
+        assert_eq!(int(&k), hex("23AF4074C90A02B3FE61D286D5C87F425E6BDD81B"));
+    }
+
+    // RFC 6979, appendix A.2.5: P-256 with SHA-256.
+    #[test]
+    fn test_rfc6979_p256_vectors() {
+        let q = hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        let x = octets("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        let vectors = [
+            ("sample", "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+            ("test", "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+        ];
+        for (msg, expected) in vectors {
+            let h1 = Sha256::digest(msg.as_bytes());
+            assert_eq!(int(&generate_k::<Sha256>(&q, &x, &h1, None)), hex(expected));
+        }
+    }
+
+    // secp256k1 vectors from python-ecdsa, bitcoinjs and trezor-crypto.
+    #[test]
+    fn test_rfc6979_secp256k1_vectors() {
+        let n = Secp256k1Params::get().n.clone();
+        let vectors = [
+            (
+                "1",
+                "Satoshi Nakamoto",
+                "8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15",
+            ),
+            (
+                "1",
+                "All those moments will be lost in time, like tears in rain. Time to die...",
+                "38AA22D72376B4DBC472E06C3BA403EE0A394DA63FC58D88686C611ABA98D6B3",
+            ),
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140",
+                "Satoshi Nakamoto",
+                "33A19B60E25FB6F4435AF53A3D42D493644827367E6453928554F43E49AA6F90",
+            ),
+            (
+                "f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181",
+                "Alan Turing",
+                "525A82B70E67874398067543FD84C83D30C175FDC45FDEEE082FE13B1D7CFDF1",
+            ),
+            (
+                "9d0219792467d7d37b4d43298a7d0c05",
+                "sample",
+                "8fa1f95d514760e498f28957b824ee6ec39ed64826ff4fecc2b5739ec45b91cd",
+            ),
+        ];
+        for (x, msg, expected) in vectors {
+            let h1 = Sha256::digest(msg.as_bytes());
+            assert_eq!(int(&generate_k::<Sha256>(&n, &octets(x), &h1, None)), hex(expected));
+        }
+    }
+
+    // Bitcoin Core, src/test/key_tests.cpp, the detsig checks: strSecret1 and
+    // strSecret2 (WIF, decoded here) sign Hash("Very deterministic message"),
+    // a double SHA-256, and must give these DER signatures.
+    #[test]
+    fn test_rfc6979_bitcoin_core_detsig() {
+        let hash = Sha256::digest(Sha256::digest(b"Very deterministic message"));
+        let vectors = [
+            (
+                "12b004fff7f4b69ef8650e767f18f11ede158148b425660723b9f9a66e61f747",
+                "304402205dbbddda71772d95ce91cd2d14b592cfbc1dd0aabd6a394b6c2d377bbe59d31d022014ddda21494a4e221f0824f0b8b924c43fa43c0ad57dccdaa11f81a6bd4582f6",
+            ),
+            (
+                "b524c28b61c9b2c49b2c7dd4c2d75887abb78768c054bd7c01af4029f6c0d117",
+                "3044022052d8a32079c11e79db95af63bb9600c5b04f21a9ca33dc129c2bfa8ac9dc1cd5022061d8ae5e0f6c1a16bde3719c64c2fd70e404b6428ab9a69566962e8771b5944d",
+            ),
+        ];
+        for (x, der) in vectors {
+            let priv_key = PrivateKey::from_bytes(&octets(x)).unwrap();
+            let signature = Signature::sign_prehashed(&hash, &priv_key).unwrap();
+            assert_eq!(signature.to_der(), octets(der));
+        }
+    }
+
+    // libsecp256k1's nonce_function_rfc6979 passes 32 bytes of ndata as the
+    // section 3.6 k'. Known answers for key 1 and digest 1, with and without
+    // ndata = [42; 32], as checked by rust-secp256k1's recovery tests.
+    #[test]
+    fn test_rfc6979_libsecp256k1_ndata() {
+        let n = Secp256k1Params::get().n.clone();
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let vectors = [
+            (
+                None,
+                "6673ffad2147741f04772b6f921f0ba6af0c1e77fc439e65c36dedf4092e8898",
+                "4c1a971652e0ada880120ef8025e709fff2080c4a39aae068d12eed009b68c89",
+            ),
+            (
+                Some([42u8; 32]),
+                "b50bb6795f31748a4d37c3a97ebd06a22ea33771040f5c05d6e2bb2d38c6227c",
+                "343b6659db969959d9fddb44bd0dd9b9dd47666ab52871901d1761eb82ec8722",
+            ),
+        ];
+        for (ndata, r, s) in vectors {
+            let k = generate_k::<Sha256>(&n, &one, &one, ndata.as_ref().map(|d| &d[..]));
+            let k = Scalar::from_bytes(&k[..].try_into().unwrap()).unwrap();
+            let r_x = Secp256k1Params::mul_generator(&k).to_affine().x.unwrap();
+            let expected_r = Scalar::from_bytes_reduced(&r_x.to_bytes());
+            assert_eq!(expected_r.to_bytes().to_vec(), octets(r));
+            let s_value = (Scalar::ONE + expected_r) * k.invert().unwrap();
+            let low_s = if s_value.is_high() { -s_value } else { s_value };
+            assert_eq!(low_s.to_bytes().to_vec(), octets(s));
+        }
+    }
+
+    #[test]
+    fn test_rfc6979_stream_is_deterministic() {
+        let n = Secp256k1Params::get().n.clone();
+        let h1 = Sha256::digest(b"sample");
+        let mut a = Rfc6979::<Sha256>::new(&n, &[1], &h1, None);
+        let mut b = Rfc6979::<Sha256>::new(&n, &[1], &h1, None);
+        let first = a.next_k();
+        assert_eq!(first, b.next_k());
+        assert_eq!(a.next_k(), b.next_k());
+        assert_ne!(first, a.next_k());
+    }
+
+    #[test]
+    fn test_rfc6979_extra_entropy() {
+        let n = Secp256k1Params::get().n.clone();
+        let h1 = Sha256::digest(b"sample");
+        let plain = generate_k::<Sha256>(&n, &[1], &h1, None);
+        let with_entropy = generate_k::<Sha256>(&n, &[1], &h1, Some(&[0x42; 32]));
+        assert_ne!(plain, with_entropy);
+        assert_eq!(with_entropy, generate_k::<Sha256>(&n, &[1], &h1, Some(&[0x42; 32])));
+        assert_ne!(with_entropy, generate_k::<Sha256>(&n, &[1], &h1, Some(&[0x43; 32])));
+    }
+
+    // The key bytes are fitted to the length of n either way.
+    #[test]
+    fn test_rfc6979_key_padding() {
+        let n = Secp256k1Params::get().n.clone();
+        let h1 = Sha256::digest(b"sample");
+        let mut padded = [0u8; 40];
+        padded[39] = 1;
+        let k = generate_k::<Sha256>(&n, &[1], &h1, None);
+        assert_eq!(k, generate_k::<Sha256>(&n, &padded, &h1, None));
+        assert_eq!(k, generate_k::<Sha256>(&n, &padded[8..], &h1, None));
+    }
+
+    #[test]
+    fn test_deterministic_signature() {
+        let priv_key = PrivateKey::from_bytes(&Scalar::ONE.to_bytes()).unwrap();
+        let sign1 = Signature::sign_message("temp msg", &priv_key).unwrap();
+        let sign2 = Signature::sign_message("temp msg", &priv_key).unwrap();
+        assert_eq!((&sign1.r, &sign1.s), (&sign2.r, &sign2.s));
+        let sign3 = Signature::sign_message_with_entropy("temp msg", &priv_key, &[1u8; 32]).unwrap();
+        assert_ne!(sign1.r, sign3.r);
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt};
+    use num_traits::One;
+
+    use crate::{arithmetic::Modular, curve::ScalarField, scalar::Scalar, secp256k1::Secp256k1Params};
+
+    fn random_pair(n: &BigInt) -> (BigInt, Scalar) {
+        let value = rand::thread_rng().gen_bigint_range(&BigInt::from(0), n);
+        let scalar = Scalar::from_bigint(&value).unwrap();
+        (value, scalar)
+    }
+
+    #[test]
+    fn test_scalar_ops_match_bigint() {
+        let n = Secp256k1Params::get().n.clone();
+        for _ in 0..200 {
+            let (a, sa) = random_pair(&n);
+            let (b, sb) = random_pair(&n);
+            assert_eq!((sa + sb).to_bigint(), (&a + &b).modulus(&n));
+            assert_eq!((sa - sb).to_bigint(), (&a - &b).modulus(&n));
+            assert_eq!((sa * sb).to_bigint(), (&a * &b).modulus(&n));
+            assert_eq!((-sa).to_bigint(), (-&a).modulus(&n));
+            if let Some(inv) = sa.invert() {
+                assert_eq!(inv.to_bigint(), a.invmod(&n).unwrap());
+                assert_eq!(inv * sa, Scalar::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_edge_cases() {
+        let n = Secp256k1Params::get().n.clone();
+        let max = Scalar::from_bigint(&(&n - BigInt::one())).unwrap();
+        assert_eq!(max, -Scalar::ONE);
+        assert_eq!(max + Scalar::ONE, Scalar::ZERO);
+        assert_eq!(Scalar::ZERO - Scalar::ONE, max);
+        assert_eq!(max * max, Scalar::ONE);
+        assert_eq!(-Scalar::ZERO, Scalar::ZERO);
+        assert_eq!(Scalar::ZERO.invert(), None);
+        // Products just below n² exercise both Barrett corrections.
+        let half = Scalar::from_bigint(&(&n >> 1u32)).unwrap();
+        assert_eq!((max * half).to_bigint(), ((&n - 1u8) * (&n >> 1u32)).modulus(&n));
+    }
+
+    #[test]
+    fn test_scalar_encoding() {
+        let n = Secp256k1Params::get().n.clone();
+        let (_, n_bytes) = n.to_bytes_be();
+        let n_bytes: [u8; 32] = n_bytes.try_into().unwrap();
+        assert_eq!(Scalar::from_bytes(&n_bytes), None);
+        assert_eq!(Scalar::from_bytes_reduced(&n_bytes), Scalar::ZERO);
+        assert_eq!(Scalar::from_bigint(&n), None);
+        assert_eq!(Scalar::from_bigint(&BigInt::from(-1)), None);
+
+        let (value, scalar) = random_pair(&n);
+        assert_eq!(Scalar::from_bytes(&scalar.to_bytes()), Some(scalar));
+        assert_eq!(scalar.to_bigint(), value);
+        assert_eq!(scalar.to_string(), value.to_string());
+        assert_eq!(scalar.bit(0), value.bit(0));
+        assert_eq!(scalar.bit(255), value.bit(255));
+    }
+
+    #[test]
+    fn test_scalar_random() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_scalar_is_high() {
+        let n = Secp256k1Params::get().n.clone();
+        let half = Scalar::from_bigint(&(&n >> 1)).unwrap();
+        assert!(!Scalar::ZERO.is_high());
+        assert!(!half.is_high());
+        assert!((half + Scalar::ONE).is_high());
+        assert!((-Scalar::ONE).is_high());
+    }
+
+    #[test]
+    fn test_scalar_batch_invert() {
+        let mut rng = rand::thread_rng();
+        let scalars = [Scalar::random(&mut rng), Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::random(&mut rng)];
+        let inverses = Scalar::batch_invert(&scalars);
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, scalar.invert().unwrap_or(Scalar::ZERO));
+        }
+        assert!(Scalar::batch_invert(&[]).is_empty());
+    }
+}
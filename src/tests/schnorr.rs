@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{curve::Field, error::{BatchError, Error}, field::FieldElement, scalar::Scalar, schnorr::{self, tagged_hash, KeyPair, Signature, XOnlyPublicKey}, secp256k1::{PrivateKey, PublicKey}};
+
+    // The test-vectors.csv file shipped with BIP340.
+    const VECTORS: &str = include_str!("bip340_vectors.csv");
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn verify_bytes(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let public_key = XOnlyPublicKey::from_bytes(public_key)?;
+        let signature = Signature::from_bytes(signature)?;
+        schnorr::verify(message, &public_key, &signature)
+    }
+
+    #[test]
+    fn test_bip340_vectors() {
+        for line in VECTORS.lines().skip(1) {
+            let fields: Vec<&str> = line.splitn(8, ',').collect();
+            let [index, secret_key, public_key, aux_rand, message, signature, result, comment] = fields[..] else {
+                panic!("malformed vector: {}", line);
+            };
+            let (public_key, message, signature) = (from_hex(public_key), from_hex(message), from_hex(signature));
+            if !secret_key.is_empty() {
+                let keypair = KeyPair::from_bytes(&from_hex(secret_key)).unwrap();
+                assert_eq!(keypair.public_key().to_bytes().to_vec(), public_key, "vector {}", index);
+                let aux_rand: [u8; 32] = from_hex(aux_rand).try_into().unwrap();
+                let own = schnorr::sign(&message, &keypair, &aux_rand).unwrap();
+                assert_eq!(own.to_bytes().to_vec(), signature, "vector {}", index);
+            }
+            let verified = verify_bytes(&public_key, &message, &signature);
+            assert_eq!(verified.is_ok(), result == "TRUE", "vector {}: {}", index, comment);
+        }
+    }
+
+    #[test]
+    fn test_bip340_rejection_reasons() {
+        let public_key = from_hex("DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659");
+        let message = from_hex("243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89");
+        let tail = "69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B";
+        let with_r = |r: &str| from_hex(&format!("{}{}", r, tail));
+
+        let r_is_p = with_r("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+        assert_eq!(Signature::from_bytes(&r_is_p), Err(Error::InvalidEncoding));
+        let s_is_n = from_hex("6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        assert_eq!(Signature::from_bytes(&s_is_n), Err(Error::InvalidScalar));
+        assert_eq!(Signature::from_bytes(&s_is_n[..63]), Err(Error::InvalidEncoding));
+
+        let not_x = with_r("4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D");
+        assert_eq!(verify_bytes(&public_key, &message, &not_x), Err(Error::InvalidSignature));
+
+        let off_curve = from_hex("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34");
+        assert_eq!(XOnlyPublicKey::from_bytes(&off_curve), Err(Error::PointNotOnCurve));
+        let above_p = from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30");
+        assert_eq!(XOnlyPublicKey::from_bytes(&above_p), Err(Error::PointNotOnCurve));
+        assert_eq!(XOnlyPublicKey::from_bytes(&public_key[1..]), Err(Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_sign_and_verify_random_keys() {
+        let mut odd_keys = 0;
+        for i in 0u8..20 {
+            let private_key = PrivateKey::generate();
+            let public_key = PublicKey::new(&private_key).unwrap();
+            odd_keys += public_key.0.y.unwrap().is_odd() as u32;
+            let keypair = KeyPair::new(&private_key);
+            assert_eq!(XOnlyPublicKey::from(&public_key), *keypair.public_key());
+            assert_eq!(public_key.to_sec1_bytes(true)[1..], keypair.public_key().to_bytes());
+            assert!(!keypair.public_key().point().y.unwrap().is_odd());
+
+            let message = [i; 40];
+            let signature = schnorr::sign(&message, &keypair, &[i; 32]).unwrap();
+            assert_eq!(schnorr::verify(&message, keypair.public_key(), &signature), Ok(()));
+            assert_eq!(Signature::from_bytes(&signature.to_bytes()), Ok(signature.clone()));
+            assert_eq!(schnorr::verify(&message[1..], keypair.public_key(), &signature), Err(Error::InvalidSignature));
+
+            // Different auxiliary randomness gives a different, equally valid signature.
+            let other = schnorr::sign(&message, &keypair, &[i ^ 1; 32]).unwrap();
+            assert_ne!(other, signature);
+            assert_eq!(schnorr::verify(&message, keypair.public_key(), &other), Ok(()));
+        }
+        assert!(odd_keys > 0);
+    }
+
+    #[test]
+    fn test_tagged_hash() {
+        // Parts are hashed as if concatenated.
+        assert_eq!(tagged_hash("BIP0340/challenge", &[b"ab", b"c"]), tagged_hash("BIP0340/challenge", &[b"abc"]));
+        assert_ne!(tagged_hash("BIP0340/challenge", &[b"abc"]), tagged_hash("BIP0340/nonce", &[b"abc"]));
+    }
+
+    #[test]
+    fn test_keypair_debug_redacted() {
+        let keypair = KeyPair::from_bytes(&[0x11; 32]).unwrap();
+        let debug = format!("{:?}", keypair);
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("1111111111"));
+        assert_eq!(KeyPair::from_bytes(&[0u8; 32]).unwrap_err(), Error::InvalidScalar);
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let messages: Vec<Vec<u8>> = (0u8..6).map(|i| vec![i; 32]).collect();
+        let keypairs: Vec<KeyPair> = messages.iter().map(|_| KeyPair::new(&PrivateKey::generate())).collect();
+        let signs: Vec<Signature> = messages.iter().zip(&keypairs)
+            .map(|(msg, keypair)| schnorr::sign(msg, keypair, &[7; 32]).unwrap())
+            .collect();
+        let items: Vec<(&XOnlyPublicKey, &[u8], &Signature)> = keypairs.iter().zip(&messages).zip(&signs)
+            .map(|((keypair, msg), sign)| (keypair.public_key(), msg.as_slice(), sign))
+            .collect();
+        assert_eq!(schnorr::verify_batch(&items), Ok(()));
+        assert_eq!(schnorr::verify_batch(&[]), Ok(()));
+        assert_eq!(schnorr::verify_batch_with_rng(&items, &mut StdRng::seed_from_u64(1)), Ok(()));
+
+        let mut tampered = items.clone();
+        tampered[3].1 = b"other msg";
+        assert_eq!(schnorr::verify_batch(&tampered), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+
+        let mut wrong_key = items.clone();
+        wrong_key[4].0 = keypairs[0].public_key();
+        assert_eq!(schnorr::verify_batch(&wrong_key), Err(BatchError { index: 4, error: Error::InvalidSignature }));
+
+        // r is not an x coordinate, which is found before the combined check;
+        // the earlier tampered entry is still the one reported.
+        let mut not_x = signs[5].clone();
+        not_x.r = not_x.r + FieldElement::ONE;
+        while XOnlyPublicKey::from_bytes(&not_x.r.to_be_bytes()).is_ok() {
+            not_x.r = not_x.r + FieldElement::ONE;
+        }
+        let mut invalid = items.clone();
+        invalid[5].2 = &not_x;
+        assert_eq!(schnorr::verify_batch(&invalid), Err(BatchError { index: 5, error: Error::InvalidSignature }));
+        invalid[3].1 = b"other msg";
+        assert_eq!(schnorr::verify_batch(&invalid), Err(BatchError { index: 3, error: Error::InvalidSignature }));
+    }
+
+    // Two bad signatures whose errors cancel in an unweighted sum.
+    #[test]
+    fn test_verify_batch_weights() {
+        let keypair = KeyPair::new(&PrivateKey::generate());
+        let (msg_a, msg_b) = ([1u8; 32], [2u8; 32]);
+        let mut sig_a = schnorr::sign(&msg_a, &keypair, &[0; 32]).unwrap();
+        let mut sig_b = schnorr::sign(&msg_b, &keypair, &[0; 32]).unwrap();
+        sig_a.s = sig_a.s + Scalar::ONE;
+        sig_b.s = sig_b.s - Scalar::ONE;
+        let items = [(keypair.public_key(), &msg_a[..], &sig_a), (keypair.public_key(), &msg_b[..], &sig_b)];
+        for seed in 0..4 {
+            let result = schnorr::verify_batch_with_rng(&items, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(result, Err(BatchError { index: 0, error: Error::InvalidSignature }));
+        }
+    }
+
+    #[test]
+    fn test_verify_batch_bip340_vectors() {
+        let parsed: Vec<(XOnlyPublicKey, Vec<u8>, Signature, bool)> = VECTORS
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.splitn(8, ',').collect();
+                let public_key = XOnlyPublicKey::from_bytes(&from_hex(fields[2])).ok()?;
+                let signature = Signature::from_bytes(&from_hex(fields[5])).ok()?;
+                Some((public_key, from_hex(fields[4]), signature, fields[6] == "TRUE"))
+            })
+            .collect();
+        let valid: Vec<(&XOnlyPublicKey, &[u8], &Signature)> = parsed
+            .iter()
+            .filter(|(.., valid)| *valid)
+            .map(|(public_key, message, signature, _)| (public_key, message.as_slice(), signature))
+            .collect();
+        assert_eq!(schnorr::verify_batch(&valid), Ok(()));
+
+        // Each failing vector is caught wherever it sits in the batch.
+        for (public_key, message, signature, _) in parsed.iter().filter(|(.., valid)| !*valid) {
+            let index = valid.len() / 2;
+            let mut batch = valid.clone();
+            batch.insert(index, (public_key, message.as_slice(), signature));
+            assert_eq!(schnorr::verify_batch(&batch), Err(BatchError { index, error: Error::InvalidSignature }));
+        }
+    }
+}
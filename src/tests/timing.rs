@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use rand::Rng;
+
+    use crate::{generator::{mul_generator, GeneratorTable}, scalar::Scalar, secp256k1::Secp256k1Params};
+
+    const SAMPLES: usize = 4000;
+    // dudect treats |t| above 4.5 as evidence of a timing leak.
+    const T_THRESHOLD: f64 = 4.5;
+
+    /// Welch's t statistic between two sets of measurements, after dropping
+    /// the slowest 5% of each class (scheduler noise, page faults).
+    fn welch_t(mut a: Vec<f64>, mut b: Vec<f64>) -> f64 {
+        for class in [&mut a, &mut b] {
+            class.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            class.truncate(class.len() * 95 / 100);
+        }
+        let stats = |v: &[f64]| {
+            let mean = v.iter().sum::<f64>() / v.len() as f64;
+            let var = v.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (v.len() - 1) as f64;
+            (mean, var, v.len() as f64)
+        };
+        let (m_a, v_a, n_a) = stats(&a);
+        let (m_b, v_b, n_b) = stats(&b);
+        (m_a - m_b) / (v_a / n_a + v_b / n_b).sqrt()
+    }
+
+    /// Runs `op` on `fixed` and on random scalars, interleaved at random,
+    /// and returns Welch's t between the two timing classes.
+    fn fixed_vs_random<T>(fixed: Scalar, op: impl Fn(&Scalar) -> T) -> f64 {
+        let mut rng = rand::thread_rng();
+        // Inputs are drawn up front so the RNG never runs between measurements.
+        let inputs: Vec<(bool, Scalar)> = (0..SAMPLES)
+            .map(|_| {
+                let use_fixed: bool = rng.gen();
+                (use_fixed, if use_fixed { fixed } else { Scalar::random(&mut rng) })
+            })
+            .collect();
+        let (mut fixed_times, mut random_times) = (Vec::new(), Vec::new());
+        for (use_fixed, k) in inputs {
+            let start = Instant::now();
+            let result = op(&k);
+            let elapsed = start.elapsed().as_nanos() as f64;
+            std::hint::black_box(result);
+            if use_fixed {
+                fixed_times.push(elapsed);
+            } else {
+                random_times.push(elapsed);
+            }
+        }
+        let t = welch_t(fixed_times, random_times);
+        println!("t = {:.2}", t);
+        t
+    }
+
+    /// dudect-style fixed-vs-random test of the Montgomery ladder. Timing is
+    /// noisy, so this only runs on request:
+    /// `cargo test --release -- --ignored --test-threads=1 timing`
+    #[test]
+    #[ignore]
+    fn test_times_ct_timing() {
+        let g = Secp256k1Params::get().g.clone();
+        let t = fixed_vs_random(Scalar::ONE, |k| g.times_ct(k));
+        assert!(t.abs() < T_THRESHOLD, "timing difference detected: t = {:.2}", t);
+    }
+
+    /// Same test for the table-based k·G used by key generation and signing.
+    /// A low byte of 0x0f makes the running sum equal the entry picked in
+    /// window 1, which a mixed addition that branches to doubling would show.
+    #[test]
+    #[ignore]
+    fn test_mul_generator_timing() {
+        GeneratorTable::get();
+        let t = fixed_vs_random(Scalar::from_u64(0x0f), mul_generator);
+        assert!(t.abs() < T_THRESHOLD, "timing difference detected: t = {:.2}", t);
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::Zero;
+
+    use crate::{arithmetic::{Modular, Secp256k1Point}, scalar::Scalar, secp256k1::Secp256k1Params, wnaf::{pippenger, wnaf, POINT_WINDOW, GENERATOR_WINDOW}};
+
+    #[test]
+    fn test_wnaf_digits() {
+        let n = Secp256k1Params::get().n.clone();
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from_u64(0xff), -Scalar::ONE];
+        for _ in 0..16 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+        for w in [POINT_WINDOW, GENERATOR_WINDOW] {
+            for k in &scalars {
+                let digits = wnaf(k, w);
+                let mut sum = BigInt::zero();
+                for (i, &d) in digits.iter().enumerate() {
+                    sum += BigInt::from(d) << i;
+                    if d != 0 {
+                        assert!(d % 2 != 0);
+                        assert!((d as i32).abs() < 1 << (w - 1));
+                        assert!(digits[i + 1..].iter().take(w - 1).all(|&next| next == 0));
+                    }
+                }
+                assert_eq!(sum.modulus(&n), k.to_bigint(), "k = {}, w = {}", k, w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        let p = g.times(&Scalar::random(&mut rng));
+        for _ in 0..8 {
+            let (u, v) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+            assert_eq!(Secp256k1Point::mul_add(&u, &p, &v), g.times(&u) + p.times(&v));
+        }
+        assert_eq!(Secp256k1Point::mul_add(&Scalar::ZERO, &p, &Scalar::ONE), p);
+        assert_eq!(Secp256k1Point::mul_add(&Scalar::ONE, &g, &-Scalar::ONE), Secp256k1Point::zero());
+        assert_eq!(Secp256k1Point::mul_add(&Scalar::ONE, &Secp256k1Point::zero(), &Scalar::ONE), g);
+    }
+
+    #[test]
+    fn test_lincomb() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        let terms: Vec<(Scalar, Secp256k1Point)> = (0..4)
+            .map(|_| (Scalar::random(&mut rng), g.times(&Scalar::random(&mut rng))))
+            .collect();
+        let expected = terms.iter().fold(Secp256k1Point::zero(), |acc, (k, p)| acc + p.times(k));
+        assert_eq!(Secp256k1Point::lincomb(&terms), expected);
+        assert_eq!(Secp256k1Point::lincomb(&[]), Secp256k1Point::zero());
+    }
+
+    #[test]
+    fn test_pippenger() {
+        let g = Secp256k1Params::get().g.clone();
+        let mut rng = rand::thread_rng();
+        for n in [1, 3, 40, 130] {
+            let mut terms: Vec<(Scalar, Secp256k1Point)> = (0..n)
+                .map(|_| (Scalar::random(&mut rng), g.times(&Scalar::random(&mut rng))))
+                .collect();
+            terms.push((Scalar::ZERO, g.clone()));
+            terms.push((-Scalar::ONE, Secp256k1Point::zero()));
+            terms.push((-Scalar::ONE, g.clone()));
+            let expected = terms.iter().fold(Secp256k1Point::zero(), |acc, (k, p)| acc + p.times(k));
+            assert_eq!(pippenger(&terms).to_affine(), expected, "n = {}", n);
+            assert_eq!(Secp256k1Point::lincomb(&terms), expected, "n = {}", n);
+        }
+        assert!(pippenger::<Secp256k1Params>(&[]).is_zero());
+        // Terms that cancel out.
+        let k = Scalar::random(&mut rng);
+        assert!(pippenger(&[(k, g.clone()), (k, -g.clone())]).is_zero());
+    }
+}
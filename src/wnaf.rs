@@ -0,0 +1,145 @@
+use num_traits::Zero;
+
+use crate::{arithmetic::{AffinePoint, ProjectivePoint}, curve::{CurveParams, ScalarField}};
+
+/// wNAF width for arbitrary points: 8 odd multiples per table.
+pub const POINT_WINDOW: usize = 5;
+/// wNAF width for G, whose 64-entry table is built once and cached.
+pub const GENERATOR_WINDOW: usize = 8;
+/// Number of terms from which `AffinePoint::lincomb` switches from Strauss
+/// to Pippenger.
+pub const PIPPENGER_THRESHOLD: usize = 128;
+
+/// Width-`w` non-adjacent form of `k`, least significant digit first. Every
+/// non-zero digit is odd and lies in (-2^(w-1), 2^(w-1)), and any `w`
+/// consecutive digits contain at most one non-zero digit. There is one
+/// digit more than the scalar has bits.
+pub fn wnaf<S: ScalarField>(k: &S, w: usize) -> Vec<i8> {
+    let window = 1u64 << w;
+    let mut k = k.limbs().to_vec();
+    k.push(0);
+    let top = k.len() - 1;
+    let mut digits = vec![0i8; 64 * top + 1];
+    let mut i = 0;
+    while k.iter().any(|&limb| limb != 0) {
+        if k[0] & 1 == 1 {
+            let m = k[0] & (window - 1);
+            if m < window / 2 {
+                digits[i] = m as i8;
+                k[0] -= m;
+            } else {
+                digits[i] = (m as i64 - window as i64) as i8;
+                // Adding 2^w - m clears the low w bits and carries upwards.
+                let mut carry = window - m;
+                for limb in k.iter_mut() {
+                    let (sum, overflow) = limb.overflowing_add(carry);
+                    *limb = sum;
+                    carry = overflow as u64;
+                }
+            }
+        }
+        for j in 0..top {
+            k[j] = (k[j] >> 1) | (k[j + 1] << 63);
+        }
+        k[top] >>= 1;
+        i += 1;
+    }
+    digits
+}
+
+/// P, 3P, 5P, ..., (2^(w-1) - 1)P. They stay in Jacobian form: one field
+/// inversion costs about as much as the additions it would save.
+pub fn odd_multiples<C: CurveParams>(p: &AffinePoint<C>, w: usize) -> Vec<ProjectivePoint<C>> {
+    let base = ProjectivePoint::from(p);
+    let double = base.double();
+    let mut points = vec![base];
+    for _ in 1..1 << (w - 2) {
+        points.push(points[points.len() - 1] + double);
+    }
+    points
+}
+
+/// Odd multiples of one point, either borrowed from a normalized cache (G)
+/// or freshly computed in Jacobian form.
+pub enum OddMultiples<'a, C: CurveParams> {
+    Affine(&'a [AffinePoint<C>]),
+    Jacobian(Vec<ProjectivePoint<C>>),
+}
+
+impl<C: CurveParams> OddMultiples<'_, C> {
+    /// acc + digit·P for an odd wNAF digit.
+    fn add_to(&self, acc: ProjectivePoint<C>, digit: i8) -> ProjectivePoint<C> {
+        let i = digit.unsigned_abs() as usize / 2;
+        match self {
+            OddMultiples::Affine(table) => match (table[i].x, table[i].y) {
+                (Some(x), Some(y)) => acc.add_affine(&x, &if digit > 0 { y } else { -y }),
+                _ => acc,
+            },
+            OddMultiples::Jacobian(table) => acc + if digit > 0 { table[i] } else { -table[i] },
+        }
+    }
+}
+
+/// Strauss–Shamir multi-scalar multiplication: Σ kᵢ·Pᵢ over a single shared
+/// doubling chain. Each term is the wNAF of kᵢ together with the odd
+/// multiples of Pᵢ for the same width. Variable time, for public scalars.
+pub fn strauss<C: CurveParams>(terms: &[(Vec<i8>, OddMultiples<C>)]) -> ProjectivePoint<C> {
+    let top = terms
+        .iter()
+        .filter_map(|(digits, _)| digits.iter().rposition(|&d| d != 0))
+        .max();
+    let mut acc = ProjectivePoint::zero();
+    let Some(top) = top else {
+        return acc;
+    };
+    for i in (0..=top).rev() {
+        acc = acc.double();
+        for (digits, table) in terms {
+            if digits[i] != 0 {
+                acc = table.add_to(acc, digits[i]);
+            }
+        }
+    }
+    acc
+}
+
+/// Pippenger's bucket method for Σ kᵢ·Pᵢ. The scalars are cut into c-bit
+/// windows; within a window every point is added once into the bucket of
+/// its digit, and the buckets are summed with a running total so that
+/// bucket j counts j times. That is about (bits / c)·(n + 2^(c+1))
+/// additions against Strauss' (bits / (w + 1))·n, which pays off once n
+/// is in the hundreds. Variable time, for public scalars.
+pub fn pippenger<C: CurveParams>(terms: &[(C::Scalar, AffinePoint<C>)]) -> ProjectivePoint<C> {
+    let bits = C::Scalar::NUM_BITS;
+    let c = pippenger_window(terms.len(), bits);
+    let mut acc = ProjectivePoint::zero();
+    for w in (0..bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+        let mut buckets = vec![ProjectivePoint::<C>::zero(); (1 << c) - 1];
+        for (k, p) in terms {
+            let digit = (0..c)
+                .filter(|&i| w * c + i < bits && k.bit(w * c + i))
+                .fold(0, |digit, i| digit | 1 << i);
+            if let (true, Some(x), Some(y)) = (digit != 0, p.x, p.y) {
+                buckets[digit - 1] = buckets[digit - 1].add_affine(&x, &y);
+            }
+        }
+        let mut running = ProjectivePoint::zero();
+        let mut sum = ProjectivePoint::zero();
+        for bucket in buckets.iter().rev() {
+            running = running + *bucket;
+            sum = sum + running;
+        }
+        acc = acc + sum;
+    }
+    acc
+}
+
+/// The window width minimising (bits / c)·(n + 2^(c+1)).
+fn pippenger_window(n: usize, bits: usize) -> usize {
+    (1..=16)
+        .min_by_key(|&c| bits.div_ceil(c) * (n + (1 << (c + 1))))
+        .expect("non-empty range")
+}